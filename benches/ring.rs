@@ -0,0 +1,50 @@
+//!Throughput of `RingBuffer` split into producer and consumer.
+//!
+//!Compare results with and without `cache-padded` feature:
+//!
+//!- `cargo bench --bench ring`
+//!- `cargo bench --bench ring --features cache-padded`
+
+use std::time::{Duration, Instant};
+
+const COUNT: usize = 10_000_000;
+const ROUNDS: usize = 5;
+
+fn run_spsc() -> Duration {
+    let mut queue = statiki::RingBuffer::<usize, 1024>::new();
+    let (producer, consumer) = queue.split();
+
+    let start = Instant::now();
+    std::thread::scope(move |scope| {
+        let consumer = scope.spawn(move || {
+            let mut received = 0;
+            while received < COUNT {
+                match consumer.pop() {
+                    Some(_) => received += 1,
+                    None => std::thread::yield_now(),
+                }
+            }
+        });
+
+        for idx in 0..COUNT {
+            while producer.try_push(idx).is_some() {
+                std::thread::yield_now();
+            }
+        }
+
+        consumer.join().expect("Success");
+    });
+    start.elapsed()
+}
+
+fn main() {
+    let padded = if cfg!(feature = "cache-padded") { "padded" } else { "unpadded" };
+
+    let mut best = Duration::MAX;
+    for _ in 0..ROUNDS {
+        best = core::cmp::min(best, run_spsc());
+    }
+
+    let throughput = COUNT as f64 / best.as_secs_f64() / 1_000_000.0;
+    println!("ring/spsc ({}): {:?} per {} elements, {:.2} M elements/s", padded, best, COUNT, throughput);
+}
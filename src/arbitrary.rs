@@ -0,0 +1,34 @@
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::Array;
+use crate::ring::RingBuffer;
+
+impl<'a, T: Arbitrary<'a>, const S: usize> Arbitrary<'a> for Array<T, S> {
+    fn arbitrary(input: &mut Unstructured<'a>) -> Result<Self> {
+        let len = input.int_in_range(0..=S)?;
+        let mut result = Self::new();
+
+        for _ in 0..len {
+            unsafe {
+                result.push_unchecked(T::arbitrary(input)?);
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+impl<'a, T: Arbitrary<'a>, const S: usize> Arbitrary<'a> for RingBuffer<T, S> {
+    fn arbitrary(input: &mut Unstructured<'a>) -> Result<Self> {
+        let len = input.int_in_range(0..=S)?;
+        let mut result = Self::new();
+
+        for _ in 0..len {
+            unsafe {
+                result.push_unchecked(T::arbitrary(input)?);
+            }
+        }
+
+        Ok(result)
+    }
+}
@@ -1,8 +1,27 @@
 //!Static vector
 //!
-use core::{mem, ptr, slice};
+use core::{fmt, mem, ptr, slice};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Error indicating insufficient capacity, holding value that didn't fit.
+pub struct CapacityError<T = ()>(pub T);
+
+impl<T> fmt::Display for CapacityError<T> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("insufficient capacity")
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: fmt::Debug> std::error::Error for CapacityError<T> {
+}
 
 ///Static array with `Vec`-like interface
+///
+///## Note:
+///
+///Zero `C` is valid: such array is always empty and rejects every element.
 pub struct Array<T, const C: usize> {
     inner: mem::MaybeUninit<[T; C]>,
     len: usize,
@@ -18,6 +37,104 @@ impl<T, const C: usize> Array<T, C> {
         }
     }
 
+    #[inline]
+    ///Creates new full instance from `data`, usable in `const` context (e.g. to initialize `static`).
+    pub const fn from_const_array(data: [T; C]) -> Self where T: Copy {
+        Self {
+            inner: mem::MaybeUninit::new(data),
+            len: C,
+        }
+    }
+
+    ///Creates new instance, copying elements from `src`.
+    ///
+    ///Returns `None` if `src` length is greater than `CAPACITY`
+    pub fn from_slice(src: &[T]) -> Option<Self> where T: Copy {
+        if src.len() > C {
+            return None;
+        }
+
+        let mut result = Self::new();
+        result.extend_from_slice(src);
+        Some(result)
+    }
+
+    ///Creates new instance, copying elements from raw `bytes`, in native byte order.
+    ///
+    ///`bytes` are not required to be aligned for `T`.
+    ///
+    ///Returns `None` if `bytes` length is not multiple of `T` size, exceeds `CAPACITY` elements
+    ///or `T` is zero-sized.
+    ///
+    ///## Safety:
+    ///
+    ///Every `T` sized chunk of `bytes` must be valid value of `T`.
+    pub unsafe fn from_bytes(bytes: &[u8]) -> Option<Self> where T: Copy {
+        let size = mem::size_of::<T>();
+        if size == 0 {
+            return None;
+        }
+        let len = bytes.len() / size;
+        if len > C || len * size != bytes.len() {
+            return None;
+        }
+
+        let mut result = Self::new();
+        ptr::copy_nonoverlapping(bytes.as_ptr(), result.as_mut_ptr() as *mut u8, bytes.len());
+        result.set_len(len);
+        Some(result)
+    }
+
+    ///Creates new instance, collecting all elements from `iter`.
+    ///
+    ///Returns `CapacityError` with first element that didn't fit, if `iter` yields more than
+    ///`CAPACITY` elements.
+    pub fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, CapacityError<T>> {
+        let mut result = Self::new();
+
+        for elem in iter {
+            result.try_push(elem)?;
+        }
+
+        Ok(result)
+    }
+
+    #[doc(hidden)]
+    ///Creates new full instance with clones of `value`, moving `value` itself into last slot.
+    ///
+    ///Used by `array!` macro.
+    pub fn __filled(value: T) -> Self where T: Clone {
+        let mut result = Self::new();
+
+        if C > 0 {
+            for _ in 1..C {
+                unsafe {
+                    result.push_unchecked(value.clone());
+                }
+            }
+            unsafe {
+                result.push_unchecked(value);
+            }
+        }
+
+        result
+    }
+
+    ///Creates new full instance, with each element at index `idx` returned by `f(idx)`.
+    ///
+    ///If `f` panics, already created elements are dropped.
+    pub fn from_fn<F: FnMut(usize) -> T>(mut f: F) -> Self {
+        let mut result = Self::new();
+
+        for idx in 0..C {
+            unsafe {
+                result.push_unchecked(f(idx));
+            }
+        }
+
+        result
+    }
+
     #[inline]
     ///Returns length of vector.
     pub const fn len(&self) -> usize {
@@ -64,6 +181,94 @@ impl<T, const C: usize> Array<T, C> {
         &mut *self.as_mut_elem(index)
     }
 
+    #[inline]
+    ///Retrieves reference to element, if `index` is within bounds.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        match index < self.len {
+            true => unsafe {
+                Some(self.get_unchecked(index))
+            },
+            false => None,
+        }
+    }
+
+    #[inline]
+    ///Retrieves mutable reference to element, if `index` is within bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        match index < self.len {
+            true => unsafe {
+                Some(self.get_unchecked_mut(index))
+            },
+            false => None,
+        }
+    }
+
+    ///Returns mutable references to elements at `indices`.
+    ///
+    ///Returns `None` if any index is out of bounds or if indices overlap.
+    pub fn get_many_mut<const N: usize>(&mut self, indices: [usize; N]) -> Option<[&mut T; N]> {
+        for (idx, index) in indices.iter().enumerate() {
+            if *index >= self.len || indices[..idx].contains(index) {
+                return None;
+            }
+        }
+
+        let ptr = self.as_mut_ptr();
+        let mut result = mem::MaybeUninit::<[&mut T; N]>::uninit();
+        let result_ptr = result.as_mut_ptr() as *mut &mut T;
+        for (idx, index) in indices.iter().enumerate() {
+            unsafe {
+                result_ptr.add(idx).write(&mut *ptr.add(*index));
+            }
+        }
+
+        unsafe {
+            Some(result.assume_init())
+        }
+    }
+
+    #[inline]
+    ///Returns reference to first element, if any.
+    pub fn first(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    #[inline]
+    ///Returns mutable reference to first element, if any.
+    pub fn first_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(0)
+    }
+
+    #[inline]
+    ///Returns reference to last element, if any.
+    pub fn last(&self) -> Option<&T> {
+        match self.len {
+            0 => None,
+            len => self.get(len - 1),
+        }
+    }
+
+    #[inline]
+    ///Returns mutable reference to last element, if any.
+    pub fn last_mut(&mut self) -> Option<&mut T> {
+        match self.len {
+            0 => None,
+            len => self.get_mut(len - 1),
+        }
+    }
+
+    #[inline]
+    ///Returns first element and the rest of elements, if any.
+    pub fn split_first(&self) -> Option<(&T, &[T])> {
+        self.as_slice().split_first()
+    }
+
+    #[inline]
+    ///Returns last element and the rest of elements, if any.
+    pub fn split_last(&self) -> Option<(&T, &[T])> {
+        self.as_slice().split_last()
+    }
+
     #[inline]
     ///Returns immutable slice with current elements
     pub fn as_slice(&self) -> &[T] {
@@ -80,25 +285,68 @@ impl<T, const C: usize> Array<T, C> {
         }
     }
 
+    #[inline]
+    ///Returns current elements as raw bytes, in native byte order.
+    ///
+    ///## Safety:
+    ///
+    ///`T` must not contain padding or other uninitialized bytes.
+    pub unsafe fn as_bytes(&self) -> &[u8] where T: Copy {
+        slice::from_raw_parts(self.as_ptr() as *const u8, self.len * mem::size_of::<T>())
+    }
+
+    #[inline]
+    ///Returns iterator over current elements.
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    #[inline]
+    ///Returns iterator over mutable references to current elements.
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, T> {
+        self.as_mut_slice().iter_mut()
+    }
+
+    #[inline]
+    ///Returns mutable slice over remaining spare capacity.
+    ///
+    ///Elements must be initialized before being included into vector via `set_len`.
+    pub fn spare_capacity_mut(&mut self) -> &mut [mem::MaybeUninit<T>] {
+        unsafe {
+            slice::from_raw_parts_mut(self.as_mut_elem(self.len) as *mut mem::MaybeUninit<T>, C - self.len)
+        }
+    }
+
+    #[inline]
+    ///Returns mutable slices over current elements and remaining spare capacity.
+    ///
+    ///Elements must be initialized before being included into vector via `set_len`.
+    pub fn split_at_spare_mut(&mut self) -> (&mut [T], &mut [mem::MaybeUninit<T>]) {
+        let ptr = self.as_mut_ptr();
+        unsafe {
+            (
+                slice::from_raw_parts_mut(ptr, self.len),
+                slice::from_raw_parts_mut(ptr.add(self.len) as *mut mem::MaybeUninit<T>, C - self.len),
+            )
+        }
+    }
+
     fn inner_truncate(&mut self, len: usize) {
-        if mem::needs_drop::<T>() {
-            loop {
-                unsafe {
-                    ptr::drop_in_place(self.as_mut_elem(self.len - 1));
-                }
-                self.len -= 1;
+        let tail_len = self.len - len;
+        //Length is updated first, so that panicking destructor cannot cause double drop.
+        self.len = len;
 
-                if self.len == len {
-                    break;
-                }
+        if mem::needs_drop::<T>() {
+            unsafe {
+                ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.as_mut_elem(len), tail_len));
             }
-        } else {
-            self.len = len;
         }
     }
 
     ///Shortens vector, keeping the first `len` elements.
     ///
+    ///Remaining elements are dropped in order, same as `Vec::truncate`.
+    ///
     ///Does nothing if `len` is greater or equal to vector length.
     pub fn truncate(&mut self, len: usize) {
         if len >= self.len {
@@ -117,6 +365,16 @@ impl<T, const C: usize> Array<T, C> {
         C
     }
 
+    ///Returns whether vector is full.
+    pub const fn is_full(&self) -> bool {
+        self.len == C
+    }
+
+    ///Returns number of elements that can be added before vector is full.
+    pub const fn remaining_capacity(&self) -> usize {
+        C - self.len
+    }
+
     ///Sets new length of vector.
     ///
     ///# Notes:
@@ -154,6 +412,52 @@ impl<T, const C: usize> Array<T, C> {
         }
     }
 
+    #[inline]
+    ///Appends element at the end.
+    ///
+    ///Returns `CapacityError` with `value` on capacity overflow
+    pub fn try_push(&mut self, value: T) -> Result<(), CapacityError<T>> {
+        match self.push(value) {
+            Some(value) => Err(CapacityError(value)),
+            None => Ok(()),
+        }
+    }
+
+    ///Appends elements from `other`, copying as many as remaining capacity allows.
+    ///
+    ///Returns number of copied elements.
+    pub fn extend_from_slice(&mut self, other: &[T]) -> usize where T: Copy {
+        let write_len = core::cmp::min(self.capacity() - self.len(), other.len());
+        let dest = self.as_mut_elem(self.len);
+        unsafe {
+            ptr::copy_nonoverlapping(other.as_ptr(), dest, write_len);
+        }
+        self.len += write_len;
+
+        write_len
+    }
+
+    ///Appends clones of elements within `range`, as many as remaining capacity allows.
+    ///
+    ///Returns number of appended elements.
+    ///
+    ///## Note:
+    ///
+    ///Panics when `range` is out of bounds
+    pub fn extend_from_within<R: core::ops::RangeBounds<usize>>(&mut self, range: R) -> usize where T: Clone {
+        let range = resolve_range(&range, self.len);
+        let write_len = core::cmp::min(self.remaining_capacity(), range.len());
+
+        for idx in range.start..range.start + write_len {
+            unsafe {
+                let elem = self.get_unchecked(idx).clone();
+                self.push_unchecked(elem);
+            }
+        }
+
+        write_len
+    }
+
     #[inline]
     ///Unconditionally retrieves element from vector.
     pub unsafe fn pop_unchecked(&mut self) -> T {
@@ -192,95 +496,788 @@ impl<T, const C: usize> Array<T, C> {
         }
     }
 
-    ///Resizes vector with provided `value`
+    #[inline]
+    ///Removes element at `index` by swapping it with last element, and popping out.
     ///
-    ///If `new_len` is greater than `len`, the `Array` is extended by the difference, with each
-    ///additional slot filled with value. If `new_len` is less than `len`, the `Array` is simply
-    ///truncated.
-    pub unsafe fn resize_unchecked(&mut self, new_len: usize, value: T) where T: Clone {
-        match new_len > self.len() {
-            true => while self.len() < new_len {
-                self.push_unchecked(value.clone());
-            },
-            false => self.truncate(new_len),
+    ///Returns `None` when `index` is out of bounds.
+    pub fn try_swap_remove(&mut self, index: usize) -> Option<T> {
+        match index < self.len {
+            true => Some(unsafe {
+                self.swap_remove_unchecked(index)
+            }),
+            false => None,
         }
     }
 
-    #[inline]
-    ///Resizes vector with provided `value`
+    ///Swaps elements at indexes `a` and `b`.
     ///
-    ///If `new_len` is greater than `len`, the `Array` is extended by the difference, with each
-    ///additional slot filled with value. If `new_len` is less than `len`, the `Array` is simply
-    ///truncated.
+    ///## Note:
+    ///
+    ///Panics when either index is out of bounds
+    pub fn swap(&mut self, a: usize, b: usize) {
+        if a >= self.len {
+            index_out_of_bounds(a, self.len);
+        } else if b >= self.len {
+            index_out_of_bounds(b, self.len);
+        }
+
+        unsafe {
+            ptr::swap(self.as_mut_elem(a), self.as_mut_elem(b));
+        }
+    }
+
+    ///Removes element at `index`, shifting all elements after it to the left, without checking
+    ///boundaries.
+    pub unsafe fn remove_unchecked(&mut self, index: usize) -> T {
+        let elem = self.as_mut_elem(index);
+        let result = ptr::read(elem);
+        ptr::copy(elem.add(1), elem, self.len - index - 1);
+        self.len -= 1;
+
+        result
+    }
+
+    ///Removes element at `index`, shifting all elements after it to the left.
     ///
     ///## Note:
     ///
-    ///Panics if `new_len` is greater than `CAPACITY`
-    pub fn resize(&mut self, new_len: usize, value: T) where T: Clone {
-        assert!(new_len <= self.capacity());
+    ///Panics when `index` is out of bounds
+    pub fn remove(&mut self, index: usize) -> T {
+        assert!(index < self.len, "removal index (is {}) should be < len (is {})", index, self.len);
         unsafe {
-            self.resize_unchecked(new_len, value);
+            self.remove_unchecked(index)
         }
     }
 
-    ///Resizes vector with default values.
+    #[inline]
+    ///Removes first element, shifting all remaining elements to the left.
     ///
-    ///If `new_len` is greater than `len`, the `Array` is extended by the difference, with each
-    ///additional slot filled with value. If `new_len` is less than `len`, the `Array` is simply
-    ///truncated.
-    pub unsafe fn resize_default_unchecked(&mut self, new_len: usize) where T: Default {
-        match new_len > self.len() {
-            true => while self.len() < new_len {
-                self.push_unchecked(T::default());
+    ///Returns `None` if vector is empty.
+    pub fn remove_front(&mut self) -> Option<T> {
+        match self.len {
+            0 => None,
+            _ => unsafe {
+                Some(self.remove_unchecked(0))
             },
-            false => self.truncate(new_len),
         }
     }
 
-    #[inline]
-    ///Resizes vector with default values.
+    ///Inserts element at `index`, shifting all elements after it to the right, without checking
+    ///capacity and boundaries.
+    pub unsafe fn insert_unchecked(&mut self, index: usize, value: T) {
+        let elem = self.as_mut_elem(index);
+        ptr::copy(elem, elem.add(1), self.len - index);
+        ptr::write(elem, value);
+        self.len += 1;
+    }
+
+    #[must_use]
+    ///Inserts element at `index`, shifting all elements after it to the right.
     ///
-    ///If `new_len` is greater than `len`, the `Array` is extended by the difference, with each
-    ///additional slot filled with value. If `new_len` is less than `len`, the `Array` is simply
-    ///truncated.
+    ///Returns `Some(T)` on capacity overflow
     ///
     ///## Note:
     ///
-    ///Panics if `new_len` is greater than `CAPACITY`
-    pub fn resize_default(&mut self, new_len: usize) where T: Default {
-        assert!(new_len <= self.capacity());
-        unsafe {
-            self.resize_default_unchecked(new_len);
+    ///Panics when `index` is greater than `len`
+    pub fn insert(&mut self, index: usize, value: T) -> Option<T> {
+        assert!(index <= self.len);
+        match self.len == self.capacity() {
+            true => Some(value),
+            false => unsafe {
+                self.insert_unchecked(index, value);
+                None
+            },
         }
     }
-}
 
-impl<T, const C: usize> Drop for Array<T, C> {
     #[inline]
-    fn drop(&mut self) {
-        self.clear();
+    ///Inserts element at `index`, shifting all elements after it to the right.
+    ///
+    ///Returns `CapacityError` with `value` on capacity overflow
+    ///
+    ///## Note:
+    ///
+    ///Panics when `index` is greater than `len`
+    pub fn try_insert(&mut self, index: usize, value: T) -> Result<(), CapacityError<T>> {
+        match self.insert(index, value) {
+            Some(value) => Err(CapacityError(value)),
+            None => Ok(()),
+        }
     }
-}
 
-impl<T, const C: usize> core::ops::Deref for Array<T, C> {
-    type Target = [T];
+    ///Inserts elements of `src` at `index`, shifting all elements after it to the right.
+    ///
+    ///Returns `CapacityError`, leaving vector unchanged, if `src` doesn't fit
+    ///
+    ///## Note:
+    ///
+    ///Panics when `index` is greater than `len`
+    pub fn insert_from_slice(&mut self, index: usize, src: &[T]) -> Result<(), CapacityError> where T: Copy {
+        assert!(index <= self.len, "insertion index (is {}) should be <= len (is {})", index, self.len);
+        if src.len() > self.remaining_capacity() {
+            return Err(CapacityError(()));
+        }
 
-    #[inline]
-    fn deref(&self) -> &Self::Target {
-        self.as_slice()
+        let elem = self.as_mut_elem(index);
+        unsafe {
+            ptr::copy(elem, elem.add(src.len()), self.len - index);
+            ptr::copy_nonoverlapping(src.as_ptr(), elem, src.len());
+        }
+        self.len += src.len();
+        Ok(())
     }
-}
 
-impl<T, const C: usize> core::ops::DerefMut for Array<T, C> {
     #[inline]
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        self.as_mut_slice()
+    ///Retains only elements for which `f` returns `true`, preserving their order.
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        self.retain_mut(|elem| f(elem))
     }
-}
 
-impl<T, const C: usize> AsRef<Array<T, C>> for Array<T, C> {
-    #[inline]
-    fn as_ref(&self) -> &Self {
+    ///Retains only elements for which `f` returns `true`, preserving their order.
+    ///
+    ///If `f` panics, already processed elements are kept compacted and unprocessed elements are
+    ///retained.
+    pub fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, mut f: F) {
+        struct Guard<'a, T, const C: usize> {
+            array: &'a mut Array<T, C>,
+            len: usize,
+            processed: usize,
+            deleted: usize,
+        }
+
+        impl<'a, T, const C: usize> Drop for Guard<'a, T, C> {
+            fn drop(&mut self) {
+                //Only non-zero when `f` panics, as otherwise all elements are processed
+                let remaining = self.len - self.processed;
+                if self.deleted > 0 && remaining > 0 {
+                    unsafe {
+                        let src = self.array.as_mut_elem(self.processed);
+                        ptr::copy(src, src.sub(self.deleted), remaining);
+                    }
+                }
+                self.array.len = self.len - self.deleted;
+            }
+        }
+
+        let len = self.len;
+        //Prevent double drop in case of panic
+        self.len = 0;
+
+        let mut guard = Guard {
+            array: self,
+            len,
+            processed: 0,
+            deleted: 0,
+        };
+
+        while guard.processed < guard.len {
+            let elem = guard.array.as_mut_elem(guard.processed);
+            let is_retained = f(unsafe { &mut *elem });
+            guard.processed += 1;
+
+            if !is_retained {
+                guard.deleted += 1;
+                unsafe {
+                    ptr::drop_in_place(elem);
+                }
+            } else if guard.deleted > 0 {
+                unsafe {
+                    ptr::copy_nonoverlapping(elem, elem.sub(guard.deleted), 1);
+                }
+            }
+        }
+    }
+
+    ///Splits vector in two at `at`, moving elements `[at..len]` into returned vector.
+    ///
+    ///## Note:
+    ///
+    ///Panics when `at` is greater than `len`
+    pub fn split_off(&mut self, at: usize) -> Self {
+        assert!(at <= self.len, "split index (is {}) should be <= len (is {})", at, self.len);
+
+        let mut result = Self::new();
+        let tail_len = self.len - at;
+        unsafe {
+            ptr::copy_nonoverlapping(self.as_mut_elem(at), result.as_mut_ptr(), tail_len);
+            result.set_len(tail_len);
+        }
+        self.len = at;
+
+        result
+    }
+
+    ///Removes elements within `range`, returning iterator over them.
+    ///
+    ///When iterator is dropped, remaining elements within `range` are dropped and elements after
+    ///`range` are moved to fill the gap.
+    ///If iterator is leaked, vector is left with elements before `range` only.
+    ///
+    ///## Note:
+    ///
+    ///Panics when `range` is out of bounds
+    pub fn drain<R: core::ops::RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T, C> {
+        let range = resolve_range(&range, self.len);
+        let tail_len = self.len - range.end;
+        self.len = range.start;
+
+        Drain {
+            array: self,
+            cursor: range.start,
+            end: range.end,
+            tail_start: range.end,
+            tail_len,
+        }
+    }
+
+    ///Resizes vector with provided `value`
+    ///
+    ///If `new_len` is greater than `len`, the `Array` is extended by the difference, with each
+    ///additional slot filled with value. If `new_len` is less than `len`, the `Array` is simply
+    ///truncated.
+    pub unsafe fn resize_unchecked(&mut self, new_len: usize, value: T) where T: Clone {
+        match new_len > self.len() {
+            true => while self.len() < new_len {
+                self.push_unchecked(value.clone());
+            },
+            false => self.truncate(new_len),
+        }
+    }
+
+    #[inline]
+    ///Resizes vector with provided `value`
+    ///
+    ///If `new_len` is greater than `len`, the `Array` is extended by the difference, with each
+    ///additional slot filled with value. If `new_len` is less than `len`, the `Array` is simply
+    ///truncated.
+    ///
+    ///## Note:
+    ///
+    ///Panics if `new_len` is greater than `CAPACITY`
+    pub fn resize(&mut self, new_len: usize, value: T) where T: Clone {
+        assert!(new_len <= self.capacity());
+        unsafe {
+            self.resize_unchecked(new_len, value);
+        }
+    }
+
+    ///Resizes vector with provided `value`
+    ///
+    ///If `new_len` is greater than `len`, the `Array` is extended by the difference, with each
+    ///additional slot filled with value. If `new_len` is less than `len`, the `Array` is simply
+    ///truncated.
+    ///
+    ///Returns `CapacityError` with `value`, leaving vector unchanged, if `new_len` is greater than `CAPACITY`
+    pub fn try_resize(&mut self, new_len: usize, value: T) -> Result<(), CapacityError<T>> where T: Clone {
+        match new_len > self.capacity() {
+            true => Err(CapacityError(value)),
+            false => unsafe {
+                self.resize_unchecked(new_len, value);
+                Ok(())
+            },
+        }
+    }
+
+    ///Resizes vector with default values.
+    ///
+    ///If `new_len` is greater than `len`, the `Array` is extended by the difference, with each
+    ///additional slot filled with value. If `new_len` is less than `len`, the `Array` is simply
+    ///truncated.
+    pub unsafe fn resize_default_unchecked(&mut self, new_len: usize) where T: Default {
+        match new_len > self.len() {
+            true => while self.len() < new_len {
+                self.push_unchecked(T::default());
+            },
+            false => self.truncate(new_len),
+        }
+    }
+
+    #[inline]
+    ///Resizes vector with default values.
+    ///
+    ///If `new_len` is greater than `len`, the `Array` is extended by the difference, with each
+    ///additional slot filled with value. If `new_len` is less than `len`, the `Array` is simply
+    ///truncated.
+    ///
+    ///## Note:
+    ///
+    ///Panics if `new_len` is greater than `CAPACITY`
+    pub fn resize_default(&mut self, new_len: usize) where T: Default {
+        assert!(new_len <= self.capacity());
+        unsafe {
+            self.resize_default_unchecked(new_len);
+        }
+    }
+
+    ///Resizes vector with values returned by `f`.
+    ///
+    ///If `new_len` is greater than `len`, the `Array` is extended by the difference, with each
+    ///additional slot filled with result of calling `f`. If `new_len` is less than `len`, the `Array` is simply
+    ///truncated.
+    ///
+    ///## Note:
+    ///
+    ///Panics if `new_len` is greater than `CAPACITY`
+    pub fn resize_with<F: FnMut() -> T>(&mut self, new_len: usize, mut f: F) {
+        assert!(new_len <= self.capacity());
+        match new_len > self.len() {
+            true => while self.len() < new_len {
+                let value = f();
+                unsafe {
+                    self.push_unchecked(value);
+                }
+            },
+            false => self.truncate(new_len),
+        }
+    }
+
+    #[inline]
+    ///Fills current elements with clones of `value`.
+    ///
+    ///Spare capacity is left untouched.
+    pub fn fill(&mut self, value: T) where T: Clone {
+        self.as_mut_slice().fill(value)
+    }
+
+    #[inline]
+    ///Fills current elements with values returned by `f`.
+    ///
+    ///Spare capacity is left untouched.
+    pub fn fill_with<F: FnMut() -> T>(&mut self, f: F) {
+        self.as_mut_slice().fill_with(f)
+    }
+
+    #[inline]
+    ///Sorts current elements, without preserving order of equal elements.
+    ///
+    ///## Note:
+    ///
+    ///Stable `sort` is only available via slice with `alloc` crate, as it requires allocation.
+    pub fn sort_unstable(&mut self) where T: Ord {
+        self.as_mut_slice().sort_unstable()
+    }
+
+    #[inline]
+    ///Sorts current elements using comparator `f`, without preserving order of equal elements.
+    pub fn sort_unstable_by<F: FnMut(&T, &T) -> core::cmp::Ordering>(&mut self, f: F) {
+        self.as_mut_slice().sort_unstable_by(f)
+    }
+
+    #[inline(always)]
+    ///Reverses order of current elements in-place.
+    ///
+    ///Spare capacity is not touched.
+    pub fn reverse(&mut self) {
+        self.as_mut_slice().reverse()
+    }
+
+    #[inline]
+    ///Rotates current elements in-place, so that element at `mid` becomes first.
+    ///
+    ///## Note:
+    ///
+    ///Panics when `mid` is greater than `len`
+    pub fn rotate_left(&mut self, mid: usize) {
+        assert!(mid <= self.len, "rotation (is {}) should be <= len (is {})", mid, self.len);
+        self.as_mut_slice().rotate_left(mid)
+    }
+
+    #[inline]
+    ///Rotates current elements in-place, so that last `k` elements become first.
+    ///
+    ///## Note:
+    ///
+    ///Panics when `k` is greater than `len`
+    pub fn rotate_right(&mut self, k: usize) {
+        assert!(k <= self.len, "rotation (is {}) should be <= len (is {})", k, self.len);
+        self.as_mut_slice().rotate_right(k)
+    }
+
+    #[inline]
+    ///Returns whether vector contains element equal to `value`.
+    pub fn contains(&self, value: &T) -> bool where T: PartialEq {
+        self.as_slice().contains(value)
+    }
+
+    #[inline]
+    ///Returns index of first element matching predicate `f`.
+    pub fn position<F: FnMut(&T) -> bool>(&self, f: F) -> Option<usize> {
+        self.as_slice().iter().position(f)
+    }
+
+    ///Converts into underlying array, if vector is full.
+    ///
+    ///Otherwise returns vector back.
+    pub fn into_array(self) -> Result<[T; C], Self> {
+        match self.len == C {
+            true => {
+                let this = mem::ManuallyDrop::new(self);
+                unsafe {
+                    Ok(ptr::read(this.inner.as_ptr()))
+                }
+            },
+            false => Err(self),
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    ///Copies current elements into new `Vec`.
+    pub fn to_vec(&self) -> alloc::vec::Vec<T> where T: Clone {
+        self.as_slice().to_vec()
+    }
+
+    #[inline]
+    ///Returns reference to underlying array, if vector is full.
+    pub fn as_full_array(&self) -> Option<&[T; C]> {
+        match self.len == C {
+            true => Some(unsafe {
+                &*self.inner.as_ptr()
+            }),
+            false => None,
+        }
+    }
+
+    #[inline]
+    ///Returns mutable reference to underlying array, if vector is full.
+    pub fn as_full_array_mut(&mut self) -> Option<&mut [T; C]> {
+        match self.len == C {
+            true => Some(unsafe {
+                &mut *self.inner.as_mut_ptr()
+            }),
+            false => None,
+        }
+    }
+
+    ///Consumes both arrays, pairing their elements into new `Array`.
+    ///
+    ///Result length is that of shorter array, truncated to `R`.
+    ///Remaining elements of both arrays are dropped.
+    pub fn zip_into<U, const S: usize, const R: usize>(self, other: Array<U, S>) -> Array<(T, U), R> {
+        let mut result = Array::new();
+
+        for pair in self.into_iter().zip(other).take(R) {
+            unsafe {
+                result.push_unchecked(pair);
+            }
+        }
+
+        result
+    }
+
+    ///Consumes vector, mapping each element with `f`.
+    ///
+    ///If `f` panics, already mapped and remaining elements are dropped.
+    pub fn map<U, F: FnMut(T) -> U>(self, mut f: F) -> Array<U, C> {
+        let mut result = Array::new();
+
+        for elem in self {
+            let elem = f(elem);
+            unsafe {
+                result.push_unchecked(elem);
+            }
+        }
+
+        result
+    }
+
+    ///Consumes vector, mapping each element with fallible `f`.
+    ///
+    ///Stops on first error, dropping already mapped and remaining elements.
+    pub fn try_map<U, E, F: FnMut(T) -> Result<U, E>>(self, mut f: F) -> Result<Array<U, C>, E> {
+        let mut result = Array::new();
+
+        for elem in self {
+            let elem = f(elem)?;
+            unsafe {
+                result.push_unchecked(elem);
+            }
+        }
+
+        Ok(result)
+    }
+
+    ///Applies `f` to each `N`-sized block of elements, starting from the end.
+    ///
+    ///Leading elements, that do not form whole block, are ignored.
+    ///
+    ///## Note:
+    ///
+    ///Panics if `N` is 0
+    pub fn for_each_rblock<const N: usize, F: FnMut(&[T; N])>(&self, mut f: F) {
+        for block in self.as_slice().rchunks_exact(N) {
+            let block = unsafe {
+                &*(block.as_ptr() as *const [T; N])
+            };
+            f(block);
+        }
+    }
+
+    #[inline]
+    ///Splits current elements into `N`-sized chunks, starting from the beginning, and remainder.
+    ///
+    ///## Note:
+    ///
+    ///Panics if `N` is 0
+    pub fn as_chunks<const N: usize>(&self) -> (&[[T; N]], &[T]) {
+        assert!(N != 0, "chunk size must be non-zero");
+
+        let chunks_len = self.len / N;
+        let (chunks, remainder) = self.as_slice().split_at(chunks_len * N);
+        let chunks = unsafe {
+            slice::from_raw_parts(chunks.as_ptr() as *const [T; N], chunks_len)
+        };
+        (chunks, remainder)
+    }
+
+    #[inline]
+    ///Splits current elements into remainder and `N`-sized chunks, starting from the end.
+    ///
+    ///## Note:
+    ///
+    ///Panics if `N` is 0
+    pub fn as_rchunks<const N: usize>(&self) -> (&[T], &[[T; N]]) {
+        assert!(N != 0, "chunk size must be non-zero");
+
+        let chunks_len = self.len / N;
+        let (remainder, chunks) = self.as_slice().split_at(self.len - chunks_len * N);
+        let chunks = unsafe {
+            slice::from_raw_parts(chunks.as_ptr() as *const [T; N], chunks_len)
+        };
+        (remainder, chunks)
+    }
+
+    #[inline(always)]
+    ///Returns iterator over `size` chunks of current elements, with last chunk possibly shorter.
+    ///
+    ///## Note:
+    ///
+    ///Panics if `size` is 0
+    pub fn chunks(&self, size: usize) -> slice::Chunks<'_, T> {
+        self.as_slice().chunks(size)
+    }
+
+    #[inline(always)]
+    ///Returns mutable iterator over `size` chunks of current elements, with last chunk possibly shorter.
+    ///
+    ///## Note:
+    ///
+    ///Panics if `size` is 0
+    pub fn chunks_mut(&mut self, size: usize) -> slice::ChunksMut<'_, T> {
+        self.as_mut_slice().chunks_mut(size)
+    }
+
+    #[inline(always)]
+    ///Returns iterator over all overlapping windows of `size` current elements.
+    ///
+    ///Yields nothing if `size` is greater than length.
+    ///
+    ///## Note:
+    ///
+    ///Panics if `size` is 0
+    ///
+    ///```
+    ///let array = statiki::array![1, 3, 5, 7, 9];
+    ///let mut averages = statiki::Array::<i32, 5>::new();
+    ///for window in array.windows(3) {
+    ///    let _ = averages.push(window.iter().sum::<i32>() / 3);
+    ///}
+    ///assert_eq!(averages, [3, 5, 7]);
+    ///```
+    pub fn windows(&self, size: usize) -> slice::Windows<'_, T> {
+        self.as_slice().windows(size)
+    }
+
+    ///Copies elements into new `Array`, skipping already seen values.
+    ///
+    ///First occurrence of each value is kept, preserving original order.
+    ///Result is truncated to `S`.
+    pub fn unique_into<const S: usize>(&self) -> Array<T, S> where T: PartialEq + Clone {
+        let mut result = Array::new();
+
+        for elem in self.as_slice() {
+            if result.len() == S {
+                break;
+            } else if !result.as_slice().contains(elem) {
+                unsafe {
+                    result.push_unchecked(elem.clone());
+                }
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<T, const C: usize> Array<T, C> {
+    ///Moves elements of `self` followed by elements of `other` into new array with capacity `C + D`.
+    ///
+    ///## Note:
+    ///
+    ///Requires nightly compiler, as it relies on unstable `generic_const_exprs`.
+    pub fn concat<const D: usize>(self, other: Array<T, D>) -> Array<T, { C + D }> where [(); C + D]: {
+        let this = mem::ManuallyDrop::new(self);
+        let other = mem::ManuallyDrop::new(other);
+        let mut result = Array::new();
+
+        unsafe {
+            ptr::copy_nonoverlapping(this.as_ptr(), result.as_mut_ptr(), this.len);
+            ptr::copy_nonoverlapping(other.as_ptr(), result.as_mut_ptr().add(this.len), other.len);
+            result.set_len(this.len + other.len);
+        }
+
+        result
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+impl<T: bytemuck::Pod, const C: usize> Array<T, C> {
+    #[inline]
+    ///Creates new full instance with all bits zeroed.
+    pub fn zeroed() -> Self {
+        Self::from(<[T; C] as bytemuck::Zeroable>::zeroed())
+    }
+
+    #[inline]
+    ///Reinterprets full array as `U`.
+    ///
+    ///Returns `None` if vector is not full, or size or alignment of `U` doesn't match.
+    ///
+    ///## Note:
+    ///
+    ///Only full vector can be cast as spare capacity is uninitialized memory, reading
+    ///which as `Pod` would be undefined behavior.
+    pub fn cast_full<U: bytemuck::Pod>(&self) -> Option<&U> {
+        bytemuck::try_cast_ref(self.as_full_array()?).ok()
+    }
+
+    #[inline]
+    ///Reinterprets full array as mutable `U`.
+    ///
+    ///Returns `None` if vector is not full, or size or alignment of `U` doesn't match.
+    pub fn cast_full_mut<U: bytemuck::Pod>(&mut self) -> Option<&mut U> {
+        bytemuck::try_cast_mut(self.as_full_array_mut()?).ok()
+    }
+}
+
+impl<T, const C: usize> Drop for Array<T, C> {
+    #[inline]
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T, const C: usize> core::ops::Deref for Array<T, C> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<T, const C: usize> core::ops::DerefMut for Array<T, C> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+#[cold]
+#[inline(never)]
+fn index_out_of_bounds(index: usize, len: usize) -> ! {
+    panic!("index {} is out of bounds of Array with len {}", index, len)
+}
+
+#[cold]
+#[inline(never)]
+fn range_out_of_bounds(start: usize, end: usize, len: usize) -> ! {
+    panic!("range {}..{} is out of bounds of Array with len {}", start, end, len)
+}
+
+fn resolve_range<R: core::ops::RangeBounds<usize>>(range: &R, len: usize) -> core::ops::Range<usize> {
+    use core::ops::Bound;
+
+    let start = match range.start_bound() {
+        Bound::Included(start) => *start,
+        Bound::Excluded(start) => start.saturating_add(1),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(end) => end.saturating_add(1),
+        Bound::Excluded(end) => *end,
+        Bound::Unbounded => len,
+    };
+
+    if start > end || end > len {
+        range_out_of_bounds(start, end, len);
+    }
+
+    start..end
+}
+
+impl<T, const C: usize> core::ops::Index<usize> for Array<T, C> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        match self.get(index) {
+            Some(elem) => elem,
+            None => index_out_of_bounds(index, self.len),
+        }
+    }
+}
+
+impl<T, const C: usize> core::ops::IndexMut<usize> for Array<T, C> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let len = self.len;
+        match self.get_mut(index) {
+            Some(elem) => elem,
+            None => index_out_of_bounds(index, len),
+        }
+    }
+}
+
+macro_rules! impl_range_index {
+    ($($range:ty),+) => {$(
+        impl<T, const C: usize> core::ops::Index<$range> for Array<T, C> {
+            type Output = [T];
+
+            #[inline]
+            fn index(&self, range: $range) -> &Self::Output {
+                let range = resolve_range(&range, self.len);
+                unsafe {
+                    self.as_slice().get_unchecked(range)
+                }
+            }
+        }
+
+        impl<T, const C: usize> core::ops::IndexMut<$range> for Array<T, C> {
+            #[inline]
+            fn index_mut(&mut self, range: $range) -> &mut Self::Output {
+                let range = resolve_range(&range, self.len);
+                unsafe {
+                    self.as_mut_slice().get_unchecked_mut(range)
+                }
+            }
+        }
+    )+};
+}
+
+impl_range_index!(
+    core::ops::Range<usize>,
+    core::ops::RangeFrom<usize>,
+    core::ops::RangeTo<usize>,
+    core::ops::RangeFull,
+    core::ops::RangeInclusive<usize>,
+    core::ops::RangeToInclusive<usize>
+);
+
+impl<T, const C: usize> AsRef<Array<T, C>> for Array<T, C> {
+    #[inline]
+    fn as_ref(&self) -> &Self {
         self
     }
 }
@@ -315,15 +1312,14 @@ impl<T: core::fmt::Debug, const C: usize> core::fmt::Debug for Array<T, C> {
 
 impl<T: Clone, const C: usize> Clone for Array<T, C> {
     fn clone(&self) -> Self {
-        let mut result = Self {
-            inner: mem::MaybeUninit::uninit(),
-            len: self.len,
-
-        };
+        let mut result = Self::new();
 
-        unsafe {
-            self.inner.as_ptr().copy_to_nonoverlapping(result.inner.as_mut_ptr(), 1);
+        for elem in self.as_slice() {
+            unsafe {
+                result.push_unchecked(elem.clone());
+            }
         }
+
         result
     }
 }
@@ -349,9 +1345,44 @@ impl<T: PartialEq, const C: usize> PartialEq<&'_ [T]> for Array<T, C> {
     }
 }
 
+impl<T: PartialEq, const C: usize, const N: usize> PartialEq<[T; N]> for Array<T, C> {
+    #[inline]
+    fn eq(&self, other: &[T; N]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T: PartialEq, const C: usize, const N: usize> PartialEq<Array<T, C>> for [T; N] {
+    #[inline]
+    fn eq(&self, other: &Array<T, C>) -> bool {
+        self == other.as_slice()
+    }
+}
+
 impl<T: Eq, const C: usize> Eq for Array<T, C> {
 }
 
+impl<T: PartialOrd, const C: usize> PartialOrd for Array<T, C> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<T: Ord, const C: usize> Ord for Array<T, C> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+impl<T: core::hash::Hash, const C: usize> core::hash::Hash for Array<T, C> {
+    #[inline]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state)
+    }
+}
+
 impl<T, const C: usize> Default for Array<T, C> {
     #[inline(always)]
     fn default() -> Self {
@@ -359,18 +1390,92 @@ impl<T, const C: usize> Default for Array<T, C> {
     }
 }
 
-#[cfg(feature = "std")]
-impl<const C: usize> std::io::Write for Array<u8, C> {
-    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
-        let write_len = core::cmp::min(self.capacity() - self.len(), buf.len());
-        let dest = self.as_mut_elem(self.len);
-        let src = buf.as_ptr();
+impl<T, const C: usize> From<[T; C]> for Array<T, C> {
+    #[inline]
+    fn from(data: [T; C]) -> Self {
+        Self {
+            inner: mem::MaybeUninit::new(data),
+            len: C,
+        }
+    }
+}
+
+///Copies elements from slice, returning it back within `CapacityError` if it doesn't fit.
+impl<'a, T: Copy, const C: usize> core::convert::TryFrom<&'a [T]> for Array<T, C> {
+    type Error = CapacityError<&'a [T]>;
+
+    #[inline]
+    fn try_from(src: &'a [T]) -> Result<Self, Self::Error> {
+        match Self::from_slice(src) {
+            Some(result) => Ok(result),
+            None => Err(CapacityError(src)),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+///Moves elements into new `Vec`.
+impl<T, const C: usize> From<Array<T, C>> for alloc::vec::Vec<T> {
+    #[inline]
+    fn from(array: Array<T, C>) -> Self {
+        let mut result = alloc::vec::Vec::with_capacity(array.len());
+        result.extend(array);
+        result
+    }
+}
+
+#[cfg(feature = "alloc")]
+///Moves elements out of `Vec`, returning it back within `CapacityError` if it doesn't fit.
+impl<T, const C: usize> core::convert::TryFrom<alloc::vec::Vec<T>> for Array<T, C> {
+    type Error = CapacityError<alloc::vec::Vec<T>>;
+
+    fn try_from(mut src: alloc::vec::Vec<T>) -> Result<Self, Self::Error> {
+        if src.len() > C {
+            return Err(CapacityError(src));
+        }
+
+        let mut result = Self::new();
         unsafe {
-            ptr::copy_nonoverlapping(src, dest, write_len);
+            ptr::copy_nonoverlapping(src.as_ptr(), result.as_mut_ptr(), src.len());
+            result.set_len(src.len());
+            //Elements are moved, so `Vec` should only de-allocate its buffer.
+            src.set_len(0);
         }
-        self.len += write_len;
+        Ok(result)
+    }
+}
+
+///Collects at most `C` elements, after which iterator is no longer polled.
+impl<T, const C: usize> core::iter::FromIterator<T> for Array<T, C> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut result = Self::new();
+
+        for elem in iter.into_iter().take(C) {
+            unsafe {
+                result.push_unchecked(elem);
+            }
+        }
+
+        result
+    }
+}
 
-        Ok(write_len)
+///Appends elements until capacity is reached, after which iterator is no longer polled.
+impl<T, const C: usize> Extend<T> for Array<T, C> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for elem in iter.into_iter().take(C - self.len) {
+            unsafe {
+                self.push_unchecked(elem);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<const C: usize> std::io::Write for Array<u8, C> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        Ok(self.extend_from_slice(buf))
     }
 
     #[inline]
@@ -379,6 +1484,71 @@ impl<const C: usize> std::io::Write for Array<u8, C> {
     }
 }
 
+///Draining iterator over `Array` elements, created by [drain](struct.Array.html#method.drain)
+pub struct Drain<'a, T, const C: usize> {
+    array: &'a mut Array<T, C>,
+    cursor: usize,
+    end: usize,
+    tail_start: usize,
+    tail_len: usize,
+}
+
+impl<'a, T, const C: usize> Iterator for Drain<'a, T, C> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.cursor < self.end {
+            let result = unsafe {
+                ptr::read(self.array.as_elem(self.cursor))
+            };
+            self.cursor += 1;
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let size = self.end - self.cursor;
+        (size, Some(size))
+    }
+}
+
+impl<'a, T, const C: usize> DoubleEndedIterator for Drain<'a, T, C> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.cursor < self.end {
+            self.end -= 1;
+            unsafe {
+                Some(ptr::read(self.array.as_elem(self.end)))
+            }
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, T, const C: usize> ExactSizeIterator for Drain<'a, T, C> {
+}
+
+impl<'a, T, const C: usize> Drop for Drain<'a, T, C> {
+    fn drop(&mut self) {
+        if mem::needs_drop::<T>() {
+            while let Some(_) = self.next() {
+            }
+        }
+
+        let start = self.array.len;
+        if self.tail_len > 0 && start != self.tail_start {
+            unsafe {
+                ptr::copy(self.array.as_elem(self.tail_start), self.array.as_mut_elem(start), self.tail_len);
+            }
+        }
+        self.array.len = start + self.tail_len;
+    }
+}
+
+///Owning iterator over `Array` elements
 pub struct ArrayConsumer<T, const C: usize> {
     inner: Array<T, C>,
     cursor: usize,
@@ -406,6 +1576,23 @@ impl<T, const C: usize> Iterator for ArrayConsumer<T, C> {
     }
 }
 
+impl<T, const C: usize> DoubleEndedIterator for ArrayConsumer<T, C> {
+    fn next_back(&mut self) -> Option<T> {
+        //Length of inner array serves as back cursor
+        if self.cursor < self.inner.len() {
+            self.inner.len -= 1;
+            unsafe {
+                Some(ptr::read(self.inner.as_elem(self.inner.len)))
+            }
+        } else {
+            None
+        }
+    }
+}
+
+impl<T, const C: usize> ExactSizeIterator for ArrayConsumer<T, C> {
+}
+
 impl<T, const C: usize> Drop for ArrayConsumer<T, C> {
     fn drop(&mut self) {
         if mem::needs_drop::<T>() {
@@ -431,3 +1618,51 @@ impl<T, const C: usize> IntoIterator for Array<T, C> {
     }
 }
 
+
+impl<'a, T, const C: usize> IntoIterator for &'a Array<T, C> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_slice().iter()
+    }
+}
+
+impl<'a, T, const C: usize> IntoIterator for &'a mut Array<T, C> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_mut_slice().iter_mut()
+    }
+}
+
+#[macro_export]
+///Creates [Array](struct.Array.html) from list of elements.
+///
+///- `array![a, b, c]` - Creates `Array` filled with provided elements, with capacity equal to
+///their number.
+///- `array![value; N]` - Creates `Array` of capacity `N`, filled with clones of `value`.
+///
+///```
+///let array = statiki::array![1, 2, 3];
+///assert_eq!(array.capacity(), 3);
+///assert_eq!(array, [1, 2, 3].as_ref());
+///
+///let array = statiki::array![0u8; 4];
+///assert_eq!(array.capacity(), 4);
+///assert_eq!(array, [0, 0, 0, 0].as_ref());
+///```
+macro_rules! array {
+    () => {
+        $crate::Array::<_, 0>::new()
+    };
+    ($value:expr; $len:expr) => {
+        $crate::Array::<_, { $len }>::__filled($value)
+    };
+    ($($value:expr),+ $(,)?) => {
+        $crate::Array::from([$($value),+])
+    };
+}
@@ -0,0 +1,18 @@
+use defmt::{Format, Formatter};
+
+use crate::Array;
+use crate::ring::RingBuffer;
+
+impl<T: Format, const S: usize> Format for Array<T, S> {
+    #[inline]
+    fn format(&self, fmt: Formatter<'_>) {
+        Format::format(self.as_slice(), fmt)
+    }
+}
+
+impl<T, const S: usize> Format for RingBuffer<T, S> {
+    #[inline]
+    fn format(&self, fmt: Formatter<'_>) {
+        defmt::write!(fmt, "RingBuffer {{ capacity: {=usize}, size: {=usize} }}", S, self.size())
+    }
+}
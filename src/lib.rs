@@ -4,24 +4,49 @@
 //!
 //!- [Array](struct.Array.html)
 //!- [RingBuffer](ring/struct.RingBuffer.html)
+//!- [LruCache](lru/struct.LruCache.html)
+//!- [StaticString](string/struct.StaticString.html)
 //!
 //!## Crate features
 //!
-//!- `std` - Enables some std interfaces (e.g. `Write`) implementations.
+//!- `alloc` - Enables some alloc interfaces (e.g. `Box`) integration.
+//!- `std` - Enables some std interfaces (e.g. `Write`) implementations. Implies `alloc`.
+//!- `cache-padded` - Places `RingBuffer` indexes on separate cache lines to avoid false sharing between producer and consumer.
 //!- `serde` - Enables serialization/deserialization implementations.
+//!- `defmt` - Enables `defmt::Format` implementations.
+//!- `bytemuck` - Enables `Pod` casts of full `Array`.
+//!- `arbitrary` - Enables `arbitrary::Arbitrary` implementations for fuzzing.
+//!- `zeroize` - Enables `zeroize::Zeroize` implementation for `Array` and wipe on drop `ZeroizingArray`.
+//!- `nightly` - Enables APIs relying on unstable `generic_const_exprs` (e.g. `Array::concat`). Requires nightly compiler.
 
 #![no_std]
 #![deny(warnings)]
 #![warn(missing_docs)]
 #![allow(clippy::style)]
+#![cfg_attr(feature = "nightly", feature(generic_const_exprs))]
+#![cfg_attr(feature = "nightly", allow(incomplete_features))]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
-mod array;
-pub use array::Array;
+pub mod array;
+pub use array::{Array, CapacityError};
 pub mod ring;
 pub use ring::RingBuffer;
+pub mod lru;
+pub use lru::LruCache;
+pub mod string;
+pub use string::StaticString;
 
 #[cfg(feature = "serde")]
 mod serde;
+#[cfg(feature = "defmt")]
+mod defmt;
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "zeroize")]
+mod zeroize;
+#[cfg(feature = "zeroize")]
+pub use self::zeroize::ZeroizingArray;
@@ -0,0 +1,131 @@
+//!LRU cache implementation
+
+use core::{fmt, mem};
+
+use crate::Array;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///Entry displaced by [put](struct.LruCache.html#method.put).
+pub enum Displaced<K, V> {
+    ///Value of already present key was replaced, holding old value.
+    Replaced(V),
+    ///Least recently used entry was evicted to make room for new one.
+    Evicted(K, V),
+}
+
+///Static LRU cache
+///
+///Entries are kept in `Array`, ordered from least to most recently used.
+///Lookup is performed via linear search, so it is suitable for small capacities only.
+pub struct LruCache<K, V, const C: usize> {
+    entries: Array<(K, V), C>,
+}
+
+impl<K, V, const C: usize> LruCache<K, V, C> {
+    #[inline]
+    ///Creates new empty instance
+    pub const fn new() -> Self {
+        Self {
+            entries: Array::new(),
+        }
+    }
+
+    #[inline]
+    ///Returns number of entries in cache.
+    pub const fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[inline]
+    ///Returns whether cache is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    #[inline]
+    ///Returns cache capacity.
+    pub const fn capacity(&self) -> usize {
+        C
+    }
+
+    #[inline]
+    ///Removes all entries from cache.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+impl<K: PartialEq, V, const C: usize> LruCache<K, V, C> {
+    #[inline]
+    fn position(&self, key: &K) -> Option<usize> {
+        self.entries.iter().position(|(entry, _)| entry == key)
+    }
+
+    #[inline]
+    ///Moves entry at `idx` to the most recently used position.
+    fn touch(&mut self, idx: usize) -> &mut (K, V) {
+        self.entries[idx..].rotate_left(1);
+        let last = self.entries.len() - 1;
+        unsafe {
+            self.entries.get_unchecked_mut(last)
+        }
+    }
+
+    ///Retrieves value by `key`, marking it as most recently used.
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        match self.position(key) {
+            Some(idx) => Some(&self.touch(idx).1),
+            None => None,
+        }
+    }
+
+    ///Retrieves mutable value by `key`, marking it as most recently used.
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        match self.position(key) {
+            Some(idx) => Some(&mut self.touch(idx).1),
+            None => None,
+        }
+    }
+
+    ///Puts new entry, marking it as most recently used.
+    ///
+    ///If `key` is already present, its value is replaced and old value is returned as `Replaced`.
+    ///Otherwise, if cache is full, the least recently used entry is evicted and returned as `Evicted`.
+    ///
+    ///Zero capacity cache evicts new entry right away.
+    pub fn put(&mut self, key: K, value: V) -> Option<Displaced<K, V>> {
+        if let Some(idx) = self.position(&key) {
+            let entry = self.touch(idx);
+            let value = mem::replace(&mut entry.1, value);
+            return Some(Displaced::Replaced(value));
+        }
+
+        let evicted = match self.entries.len() == C {
+            true if C == 0 => return Some(Displaced::Evicted(key, value)),
+            true => {
+                self.entries.rotate_left(1);
+                self.entries.pop().map(|(key, value)| Displaced::Evicted(key, value))
+            },
+            false => None,
+        };
+
+        unsafe {
+            self.entries.push_unchecked((key, value));
+        }
+        evicted
+    }
+}
+
+impl<K, V, const C: usize> Default for LruCache<K, V, C> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug, const C: usize> fmt::Debug for LruCache<K, V, C> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_map().entries(self.entries.iter().map(|(key, value)| (key, value))).finish()
+    }
+}
@@ -1,47 +1,217 @@
 //!Ring buffer implementation
 
-use core::{fmt, mem};
+use core::{fmt, mem, ptr};
 use core::cell::UnsafeCell;
 use core::sync::atomic::{AtomicUsize, Ordering};
 
+use crate::Array;
+
 struct Assert<const C: usize>;
 
 impl<const C: usize> Assert<C> {
     const RING_BUFFER_GOOD_CAPACITY: () = {
         assert!(C != 0, "Capacity cannot be 0");
-        assert!((C & (C - 1)) == 0, "Capacity is not power of 2");
+        assert!(C.is_power_of_two() || C <= usize::MAX / 4, "Capacity is too big");
     };
 }
 
+#[cfg_attr(feature = "cache-padded", repr(align(64)))]
+///Index, optionally placed on its own cache line to avoid false sharing.
+struct Index(AtomicUsize);
+
+impl core::ops::Deref for Index {
+    type Target = AtomicUsize;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
 ///Atomic ring buffer
 ///
 ///Based on <https://www.codeproject.com/Articles/43510/Lock-Free-Single-Producer-Single-Consumer-Circular>
 ///
-///When used directly, all operations are performed with relaxed ordering
+///When used directly, all operations are performed with relaxed ordering, as exclusive access is required.
+///
+///[split](#method.split) uses Acquire/Release ordering to synchronize `Producer` and `Consumer`.
+///
+///## Note:
+///
+///Capacity must be non-zero, which is validated at compile time.
+///Power of 2 capacity is recommended, as it allows to compute positions with cheap masking.
+///
+///```compile_fail
+///let buffer = statiki::RingBuffer::<u8, 0>::new();
+///```
+///
+///Zero-sized types are supported: storage occupies no space, while indexes are still tracked,
+///so `size` and destructors behave the same as for any other type.
+///
+///## Thread safety:
+///
+///Buffer is `Send` when `T: Send`, but it is not `Sync`, as `&self` methods (e.g. `peek` or `iter`)
+///access elements without synchronization.
+///To share buffer between threads use [split](#method.split), which provides `Send` producer and consumer
+///halves that can be moved into separate threads, one of each per buffer.
+///
+///```compile_fail
+///fn assert_sync<T: Sync>() {}
+///assert_sync::<statiki::RingBuffer<u8, 8>>();
+///```
 pub struct RingBuffer<T, const C: usize> {
     inner: [UnsafeCell<mem::MaybeUninit<T>>; C],
-    read: AtomicUsize,
-    write: AtomicUsize,
+    read: Index,
+    write: Index,
+    overwrite: bool,
+    overwrites: AtomicUsize,
 }
 
 impl<T, const CAPACITY: usize> RingBuffer<T, CAPACITY> {
+    #[allow(clippy::declare_interior_mutable_const)]
     const INIT: UnsafeCell<mem::MaybeUninit<T>> = UnsafeCell::new(mem::MaybeUninit::uninit());
 
     #[inline(always)]
-    ///Creates new instance
+    ///Creates new instance, which overwrites oldest elements on [push](#method.push) when full.
+    ///
+    ///Same as [new_overwrite](#method.new_overwrite).
     pub const fn new() -> Self {
+        Self::with_overwrite(true)
+    }
+
+    #[inline(always)]
+    ///Creates new instance, which overwrites oldest elements on [push](#method.push) when full.
+    pub const fn new_overwrite() -> Self {
+        Self::with_overwrite(true)
+    }
+
+    #[inline(always)]
+    ///Creates new instance, which rejects new elements on [push](#method.push) when full.
+    pub const fn new_backpressure() -> Self {
+        Self::with_overwrite(false)
+    }
+
+    ///Creates new full instance, moving elements from `data` in order, oldest first.
+    pub fn from_array(data: [T; CAPACITY]) -> Self {
+        let data = mem::ManuallyDrop::new(data);
+        let result = Self::new();
+
+        unsafe {
+            ptr::copy_nonoverlapping(data.as_ptr(), result.as_mut_ptr(), CAPACITY);
+        }
+        result.write.store(CAPACITY, Ordering::Relaxed);
+        result
+    }
+
+    #[inline(always)]
+    const fn with_overwrite(overwrite: bool) -> Self {
         let _ = Assert::<CAPACITY>::RING_BUFFER_GOOD_CAPACITY;
 
         Self {
             inner: [Self::INIT; CAPACITY],
-            read: AtomicUsize::new(0),
-            write: AtomicUsize::new(0),
+            read: Index(AtomicUsize::new(0)),
+            write: Index(AtomicUsize::new(0)),
+            overwrite,
+            overwrites: AtomicUsize::new(0),
         }
     }
 
+    #[inline(always)]
+    ///Returns whether [push](#method.push) overwrites oldest elements when buffer is full.
+    pub const fn is_overwrite(&self) -> bool {
+        self.overwrite
+    }
+
+    #[inline(always)]
+    ///Returns number of elements evicted by overwriting, since creation or last reset.
+    ///
+    ///Growing counter indicates that consumer doesn't keep up with producer.
+    pub fn overwrite_count(&self) -> usize {
+        self.overwrites.load(Ordering::Relaxed)
+    }
+
+    #[inline(always)]
+    ///Resets overwrite counter, returning its previous value.
+    pub fn reset_overwrite_count(&self) -> usize {
+        self.overwrites.swap(0, Ordering::Relaxed)
+    }
+
+    const IS_POWER_OF_TWO: bool = CAPACITY.is_power_of_two();
+
+    //Indexes wrap around on overflow when capacity is power of 2, which allows to use cheap masking.
+    //Otherwise indexes wrap around at `2 * CAPACITY`, so that full buffer is distinguishable from empty one.
+
     #[inline(always)]
     const fn mask_idx(idx: usize) -> usize {
-        idx & (CAPACITY - 1)
+        if Self::IS_POWER_OF_TWO {
+            idx & (CAPACITY - 1)
+        } else if idx >= CAPACITY {
+            idx - CAPACITY
+        } else {
+            idx
+        }
+    }
+
+    #[inline(always)]
+    ///Advances index by `count`, which must not exceed capacity.
+    const fn advance_idx(idx: usize, count: usize) -> usize {
+        if Self::IS_POWER_OF_TWO {
+            idx.wrapping_add(count)
+        } else {
+            let idx = idx + count;
+            if idx >= 2 * CAPACITY {
+                idx - 2 * CAPACITY
+            } else {
+                idx
+            }
+        }
+    }
+
+    #[inline(always)]
+    const fn retreat_idx(idx: usize) -> usize {
+        if Self::IS_POWER_OF_TWO {
+            idx.wrapping_sub(1)
+        } else if idx == 0 {
+            2 * CAPACITY - 1
+        } else {
+            idx - 1
+        }
+    }
+
+    #[inline(always)]
+    ///Returns number of elements between `read` and `write` indexes.
+    const fn distance(read: usize, write: usize) -> usize {
+        if Self::IS_POWER_OF_TWO {
+            write.wrapping_sub(read)
+        } else if write >= read {
+            write - read
+        } else {
+            write + 2 * CAPACITY - read
+        }
+    }
+
+    #[inline(always)]
+    fn as_mut_ptr(&self) -> *mut T {
+        UnsafeCell::raw_get(self.inner.as_ptr()) as *mut T
+    }
+
+    #[inline]
+    ///Returns elements as two slices, front-to-back.
+    pub(crate) fn as_slices(&self) -> (&[T], &[T]) {
+        let read = self.read.load(Ordering::Relaxed);
+        let size = Self::distance(read, self.write.load(Ordering::Relaxed));
+
+        let start = Self::mask_idx(read);
+        let head_len = core::cmp::min(size, CAPACITY - start);
+        let ptr = self.as_mut_ptr() as *const T;
+        unsafe {
+            (core::slice::from_raw_parts(ptr.add(start), head_len), core::slice::from_raw_parts(ptr, size - head_len))
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn slot(&self, idx: usize) -> *mut T {
+        self.inner.get_unchecked(Self::mask_idx(idx)).get() as *mut T
     }
 
     #[inline(always)]
@@ -53,7 +223,21 @@ impl<T, const CAPACITY: usize> RingBuffer<T, CAPACITY> {
     #[inline(always)]
     ///Returns the number of elements in buffer.
     pub fn size(&self) -> usize {
-        self.write.load(Ordering::Relaxed).wrapping_sub(self.read.load(Ordering::Relaxed))
+        Self::distance(self.read.load(Ordering::Relaxed), self.write.load(Ordering::Relaxed))
+    }
+
+    #[inline(always)]
+    ///Returns the number of elements in buffer.
+    ///
+    ///Alias to [size](#method.size)
+    pub fn len(&self) -> usize {
+        self.size()
+    }
+
+    #[inline(always)]
+    ///Returns the number of elements that can be added before buffer is full.
+    pub fn remaining_capacity(&self) -> usize {
+        CAPACITY - self.size()
     }
 
     #[inline(always)]
@@ -68,22 +252,52 @@ impl<T, const CAPACITY: usize> RingBuffer<T, CAPACITY> {
         self.size() == CAPACITY
     }
 
-    ///Adds new element, unconditionally overriding last unread element
-    pub fn push(&mut self, value: T) {
-        let write = self.write.fetch_add(1, Ordering::Relaxed);
+    #[inline(always)]
+    ///Adds new element, according to overwrite policy chosen on construction.
+    ///
+    ///When buffer is full:
+    ///
+    ///- In overwrite mode, behaves as [force_push](#method.force_push) and returns evicted element.
+    ///- In backpressure mode, behaves as [try_push](#method.try_push) and returns rejected `value`.
+    ///
+    ///## Note:
+    ///
+    ///Returned element is either evicted oldest element or `value` itself, depending on mode.
+    ///Use `force_push` or `try_push` directly, when caller needs to know which one it is.
+    pub fn push(&mut self, value: T) -> Option<T> {
+        match self.overwrite {
+            true => self.force_push(value),
+            false => self.try_push(value),
+        }
+    }
+
+    ///Adds new element, overriding oldest element when buffer is full.
+    ///
+    ///Returns overridden element, if buffer was full.
+    ///
+    ///Unlike [try_push](#method.try_push), it never rejects `value`.
+    pub fn force_push(&mut self, value: T) -> Option<T> {
+        let write = self.write.load(Ordering::Relaxed);
+        self.write.store(Self::advance_idx(write, 1), Ordering::Relaxed);
         let read = self.read.load(Ordering::Relaxed);
-        let remaning = write.wrapping_sub(read);
+        let remaning = Self::distance(read, write);
 
-        if remaning == CAPACITY {
-            unsafe {
-                (self.inner.get_unchecked(Self::mask_idx(read)).get() as *const T).read();
-            }
-            self.read.store(read.wrapping_add(1), Ordering::Relaxed);
-        }
+        let evicted = if remaning == CAPACITY {
+            let evicted = unsafe {
+                (self.inner.get_unchecked(Self::mask_idx(read)).get() as *const T).read()
+            };
+            self.read.store(Self::advance_idx(read, 1), Ordering::Relaxed);
+            self.overwrites.fetch_add(1, Ordering::Relaxed);
+            Some(evicted)
+        } else {
+            None
+        };
 
         unsafe {
             self.inner.get_unchecked(Self::mask_idx(write)).get().write(mem::MaybeUninit::new(value))
         }
+
+        evicted
     }
 
     #[inline]
@@ -91,43 +305,90 @@ impl<T, const CAPACITY: usize> RingBuffer<T, CAPACITY> {
     ///
     ///In case of buffer being full, returns `value` otherwise `None` and element is added to the buffer
     pub fn try_push(&mut self, value: T) -> Option<T> {
-        //self.inner_push(value, Ordering::Acquire, Ordering::Release)
         self.inner_push(value, Ordering::Relaxed, Ordering::Relaxed)
     }
 
+    #[inline]
+    ///Attempts to push element onto buffer, returning `Err(value)` if buffer is full.
+    ///
+    ///Same as [try_push](#method.try_push), but with `Result` suitable for `?` operator.
+    pub fn push_within_capacity(&mut self, value: T) -> Result<(), T> {
+        match self.try_push(value) {
+            None => Ok(()),
+            Some(value) => Err(value),
+        }
+    }
+
+    #[inline]
+    ///Attempts to push element onto buffer, returning new size on success.
+    ///
+    ///In case of buffer being full, returns `Err(value)`.
+    pub fn try_push_reporting(&mut self, value: T) -> Result<usize, T> {
+        match self.try_push(value) {
+            None => Ok(self.size()),
+            Some(value) => Err(value),
+        }
+    }
+
     #[inline]
     ///Attempts to push element onto buffer.
     ///
     ///In case of buffer being full, returns `value` otherwise `None` and element is added to the buffer
     fn inner_push(&self, value: T, read_op: Ordering, write_op: Ordering) -> Option<T> {
         let idx = self.write.load(Ordering::Relaxed);
-        let remaning = idx.wrapping_sub(self.read.load(read_op));
+        let remaning = Self::distance(self.read.load(read_op), idx);
 
         if remaning != CAPACITY {
             unsafe {
                 self.inner.get_unchecked(Self::mask_idx(idx)).get().write(mem::MaybeUninit::new(value))
             }
-            self.write.store(idx.wrapping_add(1), write_op);
+            self.write.store(Self::advance_idx(idx, 1), write_op);
             None
         } else {
             Some(value)
         }
     }
 
+    #[inline]
+    ///Pushes as many elements from `data` as there is free space in the buffer.
+    ///
+    ///Elements are copied in at most two steps: up to the end of underlying storage and then,
+    ///if write wraps around, from its start.
+    ///
+    ///Returns number of elements written.
+    pub fn push_slice(&mut self, data: &[T]) -> usize where T: Copy {
+        self.inner_push_slice(data, Ordering::Relaxed, Ordering::Relaxed)
+    }
+
+    fn inner_push_slice(&self, data: &[T], read_op: Ordering, write_op: Ordering) -> usize where T: Copy {
+        let write = self.write.load(Ordering::Relaxed);
+        let size = Self::distance(self.read.load(read_op), write);
+        let len = core::cmp::min(CAPACITY - size, data.len());
+
+        let start = Self::mask_idx(write);
+        let head_len = core::cmp::min(len, CAPACITY - start);
+        let ptr = self.as_mut_ptr();
+        unsafe {
+            ptr::copy_nonoverlapping(data.as_ptr(), ptr.add(start), head_len);
+            ptr::copy_nonoverlapping(data.as_ptr().add(head_len), ptr, len - head_len);
+        }
+
+        self.write.store(Self::advance_idx(write, len), write_op);
+        len
+    }
+
     #[inline]
     ///Unconditionally pushes element onto buffer.
     pub unsafe fn push_unchecked(&mut self, value: T) {
-        let idx = Self::mask_idx(
-            self.write.fetch_add(1, Ordering::Relaxed)
-        );
+        let idx = self.write.load(Ordering::Relaxed);
+        self.write.store(Self::advance_idx(idx, 1), Ordering::Relaxed);
 
-        self.inner.get_unchecked(idx).get().write(mem::MaybeUninit::new(value))
+        self.inner.get_unchecked(Self::mask_idx(idx)).get().write(mem::MaybeUninit::new(value))
     }
 
     #[inline(always)]
     ///Attempts to retrieve element from buffer.
     pub fn pop(&mut self) -> Option<T> {
-        //self.inner_pop(Ordering::Acquire, Ordering::Release)
         self.inner_pop(Ordering::Relaxed, Ordering::Relaxed)
     }
 
@@ -140,28 +401,112 @@ impl<T, const CAPACITY: usize> RingBuffer<T, CAPACITY> {
             let value = unsafe {
                 (self.inner.get_unchecked(Self::mask_idx(idx)).get() as *const T).read()
             };
-            self.read.store(idx.wrapping_add(1), read_op);
+            self.read.store(Self::advance_idx(idx, 1), read_op);
             Some(value)
         } else {
             None
         }
     }
 
+    #[inline]
+    ///Returns reference to the next element to be retrieved, without removing it.
+    ///
+    ///Must not be used while buffer is consumed concurrently (e.g. via `Consumer`), as element
+    ///can be removed while reference is alive.
+    pub fn peek(&self) -> Option<&T> {
+        self.inner_peek(Ordering::Relaxed)
+    }
+
+    #[inline]
+    fn inner_peek(&self, write_op: Ordering) -> Option<&T> {
+        let idx = self.read.load(Ordering::Relaxed);
+
+        match idx == self.write.load(write_op) {
+            true => None,
+            false => unsafe {
+                Some(&*self.slot(idx))
+            },
+        }
+    }
+
+    #[inline]
+    ///Returns reference to element `offset` positions after the next element to be retrieved.
+    ///
+    ///Offset 0 refers to the oldest element, same as [peek](#method.peek).
+    ///Returns `None` when `offset` is not less than `size`.
+    ///
+    ///Must not be used while buffer is consumed concurrently (e.g. via `Consumer`), as element
+    ///can be removed while reference is alive.
+    pub fn peek_at(&self, offset: usize) -> Option<&T> {
+        let idx = self.read.load(Ordering::Relaxed);
+
+        match offset < Self::distance(idx, self.write.load(Ordering::Relaxed)) {
+            true => unsafe {
+                Some(&*self.slot(Self::advance_idx(idx, offset)))
+            },
+            false => None,
+        }
+    }
+
+    #[inline]
+    ///Retrieves as many elements as fit into `out`.
+    ///
+    ///Elements are copied in at most two steps: up to the end of underlying storage and then,
+    ///if read wraps around, from its start.
+    ///
+    ///Returns number of elements retrieved.
+    pub fn pop_slice(&mut self, out: &mut [T]) -> usize where T: Copy {
+        self.inner_pop_slice(out, Ordering::Relaxed, Ordering::Relaxed)
+    }
+
+    ///Retrieves up to `N` elements into new `Array`, from oldest to newest.
+    pub fn pop_into<const N: usize>(&mut self) -> Array<T, N> {
+        let mut result = Array::new();
+
+        for _ in 0..core::cmp::min(N, self.size()) {
+            unsafe {
+                result.push_unchecked(self.pop_unchecked());
+            }
+        }
+
+        result
+    }
+
+    fn inner_pop_slice(&self, out: &mut [T], write_op: Ordering, read_op: Ordering) -> usize where T: Copy {
+        let read = self.read.load(Ordering::Relaxed);
+        let size = Self::distance(read, self.write.load(write_op));
+        let len = core::cmp::min(size, out.len());
+
+        let start = Self::mask_idx(read);
+        let head_len = core::cmp::min(len, CAPACITY - start);
+        let ptr = self.as_mut_ptr();
+        unsafe {
+            ptr::copy_nonoverlapping(ptr.add(start), out.as_mut_ptr(), head_len);
+            ptr::copy_nonoverlapping(ptr, out.as_mut_ptr().add(head_len), len - head_len);
+        }
+
+        self.read.store(Self::advance_idx(read, len), read_op);
+        len
+    }
+
     #[inline]
     ///Unconditionally retrieves element from buffer.
     pub unsafe fn pop_unchecked(&mut self) -> T {
-        let idx = Self::mask_idx(
-            self.read.fetch_add(1, Ordering::Relaxed)
-        );
+        let idx = self.read.load(Ordering::Relaxed);
+        self.read.store(Self::advance_idx(idx, 1), Ordering::Relaxed);
 
-        (self.inner.get_unchecked(idx).get() as *const T).read()
+        (self.inner.get_unchecked(Self::mask_idx(idx)).get() as *const T).read()
     }
 
     #[inline(always)]
     ///Removes all elements from the buffer.
-    pub fn clear(&mut self) {
+    ///
+    ///Returns number of removed elements.
+    pub fn clear(&mut self) -> usize {
+        let size = self.size();
+
         if mem::needs_drop::<T>() {
-            for _ in 0..self.size() {
+            for _ in 0..size {
                 unsafe {
                     self.pop_unchecked();
                 }
@@ -169,6 +514,89 @@ impl<T, const CAPACITY: usize> RingBuffer<T, CAPACITY> {
         } else {
             self.read.store(self.write.load(Ordering::Relaxed), Ordering::Relaxed);
         }
+
+        size
+    }
+
+    ///Retains only elements for which `f` returns `true`, dropping the rest.
+    ///
+    ///Elements are visited from oldest to newest, preserving order of retained elements.
+    ///
+    ///## Note:
+    ///
+    ///If `f` panics, element being visited is dropped and order of remaining elements is unspecified.
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        for _ in 0..self.size() {
+            unsafe {
+                let value = self.pop_unchecked();
+                if f(&value) {
+                    self.push_unchecked(value);
+                }
+            }
+        }
+    }
+
+    #[inline]
+    ///Returns iterator over references to stored elements, from oldest to newest.
+    ///
+    ///Must not be used while buffer is accessed concurrently (e.g. via `Producer` or `Consumer`),
+    ///as elements can be overwritten or removed while references are alive.
+    pub fn iter(&self) -> RingIter<'_, T, CAPACITY> {
+        let front = self.read.load(Ordering::Relaxed);
+        let back = self.write.load(Ordering::Relaxed);
+
+        RingIter {
+            buffer: self,
+            front,
+            back,
+        }
+    }
+
+    #[inline(always)]
+    ///Returns iterator, that removes elements from the buffer.
+    ///
+    ///Elements, that were not consumed, are dropped together with iterator.
+    pub fn drain(&mut self) -> Drain<'_, T, CAPACITY> {
+        Drain(self)
+    }
+
+    ///Returns median of currently stored elements.
+    ///
+    ///Elements are cloned into temporary `Array` and sorted, leaving buffer intact.
+    ///In case of even number of elements, lower one is returned.
+    ///
+    ///Returns `None` if buffer is empty.
+    pub fn median(&self) -> Option<T> where T: Ord + Clone {
+        self.percentile(50)
+    }
+
+    ///Returns `p`-th percentile of currently stored elements, using nearest rank below.
+    ///
+    ///Elements are cloned into temporary `Array` and sorted, leaving buffer intact.
+    ///
+    ///Returns `None` if buffer is empty.
+    ///
+    ///## Note:
+    ///
+    ///Panics if `p` is greater than 100
+    pub fn percentile(&self, p: u8) -> Option<T> where T: Ord + Clone {
+        assert!(p <= 100);
+
+        let size = self.size();
+        if size == 0 {
+            return None;
+        }
+
+        let mut sorted = Array::<T, CAPACITY>::new();
+        for elem in self.iter() {
+            unsafe {
+                sorted.push_unchecked(elem.clone());
+            }
+        }
+        sorted.sort_unstable();
+
+        let idx = (size - 1) * p as usize / 100;
+        Some(sorted.swap_remove(idx))
     }
 
     #[inline(always)]
@@ -176,6 +604,19 @@ impl<T, const CAPACITY: usize> RingBuffer<T, CAPACITY> {
     pub fn split(&mut self) -> (Producer<'_, T, CAPACITY>, Consumer<'_, T, CAPACITY>) {
         (Producer(self), Consumer(self))
     }
+
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    ///Leaks boxed buffer, splitting it into producer and consumer with `'static` lifetime.
+    ///
+    ///Buffer is never de-allocated, so it is intended for buffers that live until program ends
+    ///(e.g. shared between long-lived threads).
+    pub fn leak_split(self: alloc::boxed::Box<Self>) -> (Producer<'static, T, CAPACITY>, Consumer<'static, T, CAPACITY>) where T: 'static {
+        alloc::boxed::Box::leak(self).split()
+    }
+}
+
+unsafe impl<T: Send, const CAPACITY: usize> Send for RingBuffer<T, CAPACITY> {
 }
 
 impl<T, const CAPACITY: usize> Drop for RingBuffer<T, CAPACITY> {
@@ -192,6 +633,65 @@ impl<T, const CAPACITY: usize> Default for RingBuffer<T, CAPACITY> {
     }
 }
 
+///Pushes elements according to overwrite policy.
+///
+///In overwrite mode, iterator is consumed in full and only last `CAPACITY` elements remain.
+///In backpressure mode, extending stops once buffer is full, dropping first element that didn't fit.
+impl<T, const CAPACITY: usize> Extend<T> for RingBuffer<T, CAPACITY> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        match self.overwrite {
+            true => for elem in iter {
+                self.force_push(elem);
+            },
+            false => for elem in iter {
+                if self.try_push(elem).is_some() {
+                    break;
+                }
+            },
+        }
+    }
+}
+
+///Collects all elements into buffer in overwrite mode, overriding oldest elements when buffer is full.
+///
+///Unlike `Array`, iterator is always consumed in full and only last `CAPACITY` elements remain.
+impl<T, const CAPACITY: usize> core::iter::FromIterator<T> for RingBuffer<T, CAPACITY> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut result = Self::new();
+        result.extend(iter);
+        result
+    }
+}
+
+///Iterates over references to stored elements, from oldest to newest.
+///
+///Same as [iter](struct.RingBuffer.html#method.iter), hence must not be used while buffer is accessed concurrently.
+impl<'a, T, const CAPACITY: usize> IntoIterator for &'a RingBuffer<T, CAPACITY> {
+    type Item = &'a T;
+    type IntoIter = RingIter<'a, T, CAPACITY>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: Clone, const CAPACITY: usize> Clone for RingBuffer<T, CAPACITY> {
+    fn clone(&self) -> Self {
+        let mut result = Self::with_overwrite(self.overwrite);
+        let (head, tail) = self.as_slices();
+
+        for elem in head.iter().chain(tail.iter()) {
+            unsafe {
+                result.push_unchecked(elem.clone());
+            }
+        }
+
+        result
+    }
+}
+
 impl<T, const CAPACITY: usize> fmt::Debug for RingBuffer<T, CAPACITY> {
     #[inline(always)]
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -202,6 +702,240 @@ impl<T, const CAPACITY: usize> fmt::Debug for RingBuffer<T, CAPACITY> {
     }
 }
 
+///Writes string bytes according to overwrite policy.
+///
+///In overwrite mode, oldest bytes are overridden when buffer is full.
+///In backpressure mode, fails without writing anything if `text` doesn't fit.
+impl<const CAPACITY: usize> fmt::Write for RingBuffer<u8, CAPACITY> {
+    fn write_str(&mut self, text: &str) -> fmt::Result {
+        match self.overwrite {
+            true => for byte in text.bytes() {
+                self.force_push(byte);
+            },
+            false => if self.remaining_capacity() < text.len() {
+                return Err(fmt::Error);
+            } else {
+                self.push_slice(text.as_bytes());
+            },
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<const CAPACITY: usize> RingBuffer<u8, CAPACITY> {
+    ///Reads bytes from `reader` directly into buffer storage, in at most two reads.
+    ///
+    ///In overwrite mode up to `CAPACITY` bytes are read, evicting oldest bytes as necessary.
+    ///In backpressure mode only free space is filled.
+    ///
+    ///Stops once `reader` returns less than requested.
+    ///Returns number of bytes read.
+    ///
+    ///Fails with `InvalidData` if `reader` reports more bytes than requested.
+    ///
+    ///## Note:
+    ///
+    ///In overwrite mode, slice given to `reader` also covers oldest bytes, which are evicted only
+    ///once `reader` returns.
+    ///If `reader` modifies slice past returned count, or fails, these bytes are corrupted.
+    pub fn write_from_reader<R: std::io::Read>(&mut self, reader: &mut R) -> std::io::Result<usize> {
+        let limit = match self.overwrite {
+            true => CAPACITY,
+            false => self.remaining_capacity(),
+        };
+        let mut total = 0;
+
+        while total < limit {
+            let write = self.write.load(Ordering::Relaxed);
+            let start = Self::mask_idx(write);
+            let len = core::cmp::min(limit - total, CAPACITY - start);
+            let buf = unsafe {
+                let ptr = self.as_mut_ptr().add(start);
+                //Free space is uninitialized, so it must be zeroed before handing it to reader.
+                ptr::write_bytes(ptr, 0, core::cmp::min(len, self.remaining_capacity()));
+                core::slice::from_raw_parts_mut(ptr, len)
+            };
+
+            let written = reader.read(buf)?;
+            if written > len {
+                return Err(std::io::Error::new(std::io::ErrorKind::InvalidData, "reader returned more bytes than requested"));
+            }
+            let read = self.read.load(Ordering::Relaxed);
+            let size = Self::distance(read, write) + written;
+            self.write.store(Self::advance_idx(write, written), Ordering::Relaxed);
+            if size > CAPACITY {
+                self.read.store(Self::advance_idx(read, size - CAPACITY), Ordering::Relaxed);
+                self.overwrites.fetch_add(size - CAPACITY, Ordering::Relaxed);
+            }
+
+            total += written;
+            if written < len {
+                break;
+            }
+        }
+
+        Ok(total)
+    }
+
+    ///Writes stored bytes directly from buffer storage into `writer`, in at most two writes.
+    ///
+    ///Written bytes are removed from the buffer.
+    ///Stops once `writer` accepts less than provided.
+    ///Returns number of bytes written.
+    ///
+    ///Fails with `InvalidData` if `writer` reports more bytes than provided.
+    pub fn read_to_writer<W: std::io::Write>(&mut self, writer: &mut W) -> std::io::Result<usize> {
+        let mut total = 0;
+
+        loop {
+            let (head, _) = self.as_slices();
+            let len = head.len();
+            if len == 0 {
+                break;
+            }
+
+            let written = writer.write(head)?;
+            if written > len {
+                return Err(std::io::Error::new(std::io::ErrorKind::InvalidData, "writer accepted more bytes than provided"));
+            }
+            let read = self.read.load(Ordering::Relaxed);
+            self.read.store(Self::advance_idx(read, written), Ordering::Relaxed);
+
+            total += written;
+            if written < len {
+                break;
+            }
+        }
+
+        Ok(total)
+    }
+}
+
+#[cfg(feature = "std")]
+///Writes bytes according to overwrite policy.
+///
+///In overwrite mode, oldest bytes are overridden when buffer is full, hence write always succeeds in full.
+///In backpressure mode, only free space is filled, hence write returns 0 when buffer is full.
+impl<const CAPACITY: usize> std::io::Write for RingBuffer<u8, CAPACITY> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match self.overwrite {
+            true => for byte in buf {
+                self.force_push(*byte);
+            },
+            false => return Ok(self.push_slice(buf)),
+        }
+
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+///Reads bytes from the front of the buffer.
+///
+///When buffer is empty, read returns 0, which is treated as end of stream by `Read` users.
+impl<const CAPACITY: usize> std::io::Read for RingBuffer<u8, CAPACITY> {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        Ok(self.pop_slice(buf))
+    }
+}
+
+///Draining iterator over RingBuffer elements, created by [drain](struct.RingBuffer.html#method.drain)
+pub struct Drain<'a, T, const N: usize>(&'a mut RingBuffer<T, N>);
+
+impl<'a, T, const N: usize> Iterator for Drain<'a, T, N> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        match self.0.is_empty() {
+            true => None,
+            false => unsafe {
+                Some(self.0.pop_unchecked())
+            },
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let size = self.0.size();
+        (size, Some(size))
+    }
+}
+
+impl<'a, T, const N: usize> ExactSizeIterator for Drain<'a, T, N> {
+}
+
+impl<'a, T, const N: usize> Drop for Drain<'a, T, N> {
+    #[inline(always)]
+    fn drop(&mut self) {
+        self.0.clear();
+    }
+}
+
+///Iterator over RingBuffer elements, created by [iter](struct.RingBuffer.html#method.iter)
+pub struct RingIter<'a, T, const N: usize> {
+    buffer: &'a RingBuffer<T, N>,
+    front: usize,
+    back: usize,
+}
+
+impl<'a, T, const N: usize> Iterator for RingIter<'a, T, N> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.front == self.back {
+            true => None,
+            false => unsafe {
+                let elem = &*self.buffer.slot(self.front);
+                self.front = RingBuffer::<T, N>::advance_idx(self.front, 1);
+                Some(elem)
+            },
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let size = RingBuffer::<T, N>::distance(self.front, self.back);
+        (size, Some(size))
+    }
+}
+
+impl<'a, T, const N: usize> DoubleEndedIterator for RingIter<'a, T, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.front == self.back {
+            true => None,
+            false => unsafe {
+                self.back = RingBuffer::<T, N>::retreat_idx(self.back);
+                Some(&*self.buffer.slot(self.back))
+            },
+        }
+    }
+}
+
+impl<'a, T, const N: usize> ExactSizeIterator for RingIter<'a, T, N> {
+}
+
+impl<'a, T, const N: usize> Clone for RingIter<'a, T, N> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self {
+            buffer: self.buffer,
+            front: self.front,
+            back: self.back,
+        }
+    }
+}
+
 ///Consumer of Ring RingBuffer
 ///
 ///As name implies, it is only capable of consuming elements from buffer
@@ -213,11 +947,64 @@ impl<'a, T, const N: usize> Consumer<'a, T, N> {
     pub fn pop(&self) -> Option<T> {
         self.0.inner_pop(Ordering::Acquire, Ordering::Release)
     }
+
+    #[inline]
+    ///Returns reference to the next element to be retrieved, without removing it.
+    ///
+    ///Requires exclusive access, so that reference cannot outlive next retrieval.
+    ///
+    ///```compile_fail
+    ///let mut queue = statiki::RingBuffer::<String, 4>::new();
+    ///let (producer, mut consumer) = queue.split();
+    ///assert!(producer.try_push(String::from("hello")).is_none());
+    ///
+    ///let value = consumer.peek().expect("To peek");
+    ///consumer.pop();
+    ///assert_eq!(value, "hello");
+    ///```
+    pub fn peek(&mut self) -> Option<&T> {
+        self.0.inner_peek(Ordering::Acquire)
+    }
+
+    #[inline]
+    ///Retrieves as many elements as fit into `out`.
+    ///
+    ///Elements are copied with single memcpy per contiguous region and read index is
+    ///committed once per call.
+    ///
+    ///Returns number of elements retrieved.
+    pub fn pop_slice(&self, out: &mut [T]) -> usize where T: Copy {
+        self.0.inner_pop_slice(out, Ordering::Acquire, Ordering::Release)
+    }
 }
 
-unsafe impl<'a, T, const N: usize> Sync for Consumer<'a, T, N> {
+///Retrieves elements via [pop](struct.Consumer.html#method.pop).
+///
+///## Note:
+///
+///Iterator returns `None` when buffer is empty at the moment, but can yield elements again
+///once producer pushes more, hence it is not fused.
+impl<'a, T, const N: usize> Iterator for Consumer<'a, T, N> {
+    type Item = T;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.pop()
+    }
 }
-unsafe impl<'a, T, const N: usize> Send for Consumer<'a, T, N> {
+
+///Retrieves elements via [pop](struct.Consumer.html#method.pop), allowing `for item in &consumer`.
+impl<'a, 'b, T, const N: usize> Iterator for &'b Consumer<'a, T, N> {
+    type Item = T;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.pop()
+    }
+}
+
+//Only single instance exists per buffer, so it can be sent, but not shared, across threads.
+unsafe impl<'a, T: Send, const N: usize> Send for Consumer<'a, T, N> {
 }
 impl<'a, T, const N: usize> fmt::Debug for Consumer<'a, T, N> {
     #[inline(always)]
@@ -239,11 +1026,21 @@ impl<'a, T, const N: usize> Producer<'a, T, N> {
     pub fn try_push(&self, value: T) -> Option<T> {
         self.0.inner_push(value, Ordering::Acquire, Ordering::Release)
     }
-}
 
-unsafe impl<'a, T, const N: usize> Sync for Producer<'a, T, N> {
+    #[inline]
+    ///Pushes as many elements from `data` as there is free space in the buffer.
+    ///
+    ///Elements are copied with single memcpy per contiguous region and write index is
+    ///committed once per call.
+    ///
+    ///Returns number of elements written.
+    pub fn push_slice(&self, data: &[T]) -> usize where T: Copy {
+        self.0.inner_push_slice(data, Ordering::Acquire, Ordering::Release)
+    }
 }
-unsafe impl<'a, T, const N: usize> Send for Producer<'a, T, N> {
+
+//Only single instance exists per buffer, so it can be sent, but not shared, across threads.
+unsafe impl<'a, T: Send, const N: usize> Send for Producer<'a, T, N> {
 }
 impl<'a, T, const N: usize> fmt::Debug for Producer<'a, T, N> {
     #[inline(always)]
@@ -5,6 +5,8 @@ use core::marker::PhantomData;
 use core::fmt;
 
 use crate::Array;
+use crate::ring::RingBuffer;
+use crate::StaticString;
 
 impl<T: Serialize, const S: usize> Serialize for Array<T, S> {
     #[inline]
@@ -41,3 +43,73 @@ impl<'de, T: Deserialize<'de>, const S: usize> Deserialize<'de> for Array<T, S>
         des.deserialize_seq(ArrayVisitor(PhantomData))
     }
 }
+
+impl<T: Serialize, const S: usize> Serialize for RingBuffer<T, S> {
+    #[inline]
+    fn serialize<SER: Serializer>(&self, ser: SER) -> Result<SER::Ok, SER::Error> {
+        let (head, tail) = self.as_slices();
+        ser.collect_seq(head.iter().chain(tail.iter()))
+    }
+}
+
+impl<'de, T: Deserialize<'de>, const S: usize> Deserialize<'de> for RingBuffer<T, S> {
+    fn deserialize<D: Deserializer<'de>>(des: D) -> Result<Self, D::Error> {
+        struct RingVisitor<T, const S: usize>(PhantomData<[T; S]>);
+
+        impl<'de, T: Deserialize<'de>, const S: usize> Visitor<'de> for RingVisitor<T, S> {
+            type Value = RingBuffer<T, S>;
+
+            #[inline]
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a capped sequence")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut result = Self::Value::new();
+
+                while let Some(value) = seq.next_element()? {
+                    if result.try_push(value).is_some() {
+                        return Err(serde::de::Error::custom(format_args!("Capacity({}) overflow", S)));
+                    }
+                }
+
+                Ok(result)
+            }
+        }
+
+        des.deserialize_seq(RingVisitor(PhantomData))
+    }
+}
+
+impl<const S: usize> Serialize for StaticString<S> {
+    #[inline]
+    fn serialize<SER: Serializer>(&self, ser: SER) -> Result<SER::Ok, SER::Error> {
+        ser.serialize_str(self.as_str())
+    }
+}
+
+impl<'de, const S: usize> Deserialize<'de> for StaticString<S> {
+    fn deserialize<D: Deserializer<'de>>(des: D) -> Result<Self, D::Error> {
+        struct StringVisitor<const S: usize>;
+
+        impl<'de, const S: usize> Visitor<'de> for StringVisitor<S> {
+            type Value = StaticString<S>;
+
+            #[inline]
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a capped string")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, text: &str) -> Result<Self::Value, E> {
+                let mut result = Self::Value::new();
+
+                match result.push_str(text) {
+                    Ok(()) => Ok(result),
+                    Err(_) => Err(E::custom(format_args!("Capacity({}) overflow", S))),
+                }
+            }
+        }
+
+        des.deserialize_str(StringVisitor)
+    }
+}
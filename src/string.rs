@@ -0,0 +1,161 @@
+//!Static string
+
+use core::{fmt, str};
+
+use crate::{Array, CapacityError};
+
+///Static string with fixed capacity of `C` bytes
+///
+///Content is always valid UTF-8.
+pub struct StaticString<const C: usize> {
+    inner: Array<u8, C>,
+}
+
+impl<const C: usize> StaticString<C> {
+    #[inline]
+    ///Creates new empty instance
+    pub const fn new() -> Self {
+        Self {
+            inner: Array::new(),
+        }
+    }
+
+    #[inline]
+    ///Returns length in bytes.
+    pub const fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    #[inline]
+    ///Returns whether string is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    #[inline]
+    ///Returns capacity in bytes.
+    pub const fn capacity(&self) -> usize {
+        C
+    }
+
+    #[inline]
+    ///Returns remaining capacity in bytes.
+    pub const fn remaining_capacity(&self) -> usize {
+        self.inner.remaining_capacity()
+    }
+
+    #[inline]
+    ///Returns string slice.
+    pub fn as_str(&self) -> &str {
+        unsafe {
+            str::from_utf8_unchecked(self.inner.as_slice())
+        }
+    }
+
+    #[inline]
+    ///Removes all content.
+    pub fn clear(&mut self) {
+        self.inner.clear();
+    }
+
+    ///Appends whole `text`.
+    ///
+    ///Returns `CapacityError` without modifying string when `text` doesn't fit.
+    pub fn push_str(&mut self, text: &str) -> Result<(), CapacityError> {
+        match text.len() > self.remaining_capacity() {
+            true => Err(CapacityError(())),
+            false => {
+                self.inner.extend_from_slice(text.as_bytes());
+                Ok(())
+            },
+        }
+    }
+
+    ///Appends character.
+    ///
+    ///Returns `CapacityError` with `ch` when its UTF-8 encoding doesn't fit.
+    pub fn push(&mut self, ch: char) -> Result<(), CapacityError<char>> {
+        let mut buf = [0u8; 4];
+        match self.push_str(ch.encode_utf8(&mut buf)) {
+            Ok(()) => Ok(()),
+            Err(_) => Err(CapacityError(ch)),
+        }
+    }
+}
+
+impl<const C: usize> core::ops::Deref for StaticString<C> {
+    type Target = str;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl<const C: usize> AsRef<str> for StaticString<C> {
+    #[inline(always)]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const C: usize> Default for StaticString<C> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const C: usize> Clone for StaticString<C> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<const C: usize> PartialEq for StaticString<C> {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<const C: usize> PartialEq<str> for StaticString<C> {
+    #[inline(always)]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<const C: usize> PartialEq<&'_ str> for StaticString<C> {
+    #[inline(always)]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<const C: usize> Eq for StaticString<C> {
+}
+
+impl<const C: usize> fmt::Write for StaticString<C> {
+    #[inline]
+    fn write_str(&mut self, text: &str) -> fmt::Result {
+        self.push_str(text).map_err(|_| fmt::Error)
+    }
+}
+
+impl<const C: usize> fmt::Debug for StaticString<C> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), fmt)
+    }
+}
+
+impl<const C: usize> fmt::Display for StaticString<C> {
+    #[inline(always)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), fmt)
+    }
+}
@@ -0,0 +1,86 @@
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use core::ops;
+
+use crate::Array;
+
+impl<T: Zeroize, const S: usize> Array<T, S> {
+    #[inline]
+    ///Zeroes current elements, clears vector and then zeroes whole storage.
+    ///
+    ///Unlike `zeroize`, it also wipes spare capacity, which may hold bytes of previously removed elements.
+    pub fn zeroize_full(&mut self) {
+        self.zeroize();
+        self.spare_capacity_mut().zeroize();
+    }
+}
+
+///Zeroes current elements and clears vector.
+///
+///Spare capacity is not touched, use [zeroize_full](../struct.Array.html#method.zeroize_full) to wipe it too.
+///To wipe whole storage on drop, use [ZeroizingArray](../struct.ZeroizingArray.html).
+impl<T: Zeroize, const S: usize> Zeroize for Array<T, S> {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.iter_mut().zeroize();
+        self.clear();
+    }
+}
+
+///`Array` wrapper, which wipes whole storage, including spare capacity, on drop.
+pub struct ZeroizingArray<T: Zeroize, const S: usize>(Array<T, S>);
+
+impl<T: Zeroize, const S: usize> ZeroizingArray<T, S> {
+    #[inline]
+    ///Creates new empty instance
+    pub const fn new() -> Self {
+        Self(Array::new())
+    }
+}
+
+impl<T: Zeroize, const S: usize> ops::Deref for ZeroizingArray<T, S> {
+    type Target = Array<T, S>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: Zeroize, const S: usize> ops::DerefMut for ZeroizingArray<T, S> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T: Zeroize, const S: usize> Default for ZeroizingArray<T, S> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Zeroize, const S: usize> From<Array<T, S>> for ZeroizingArray<T, S> {
+    #[inline(always)]
+    fn from(array: Array<T, S>) -> Self {
+        Self(array)
+    }
+}
+
+impl<T: Zeroize, const S: usize> Zeroize for ZeroizingArray<T, S> {
+    #[inline(always)]
+    fn zeroize(&mut self) {
+        self.0.zeroize_full();
+    }
+}
+
+impl<T: Zeroize, const S: usize> Drop for ZeroizingArray<T, S> {
+    #[inline(always)]
+    fn drop(&mut self) {
+        self.0.zeroize_full();
+    }
+}
+
+impl<T: Zeroize, const S: usize> ZeroizeOnDrop for ZeroizingArray<T, S> {
+}
@@ -0,0 +1,22 @@
+#![cfg(feature = "arbitrary")]
+
+use arbitrary::{Arbitrary, Unstructured};
+use statiki::{Array, RingBuffer};
+
+#[test]
+fn test_arbitrary_within_capacity() {
+    let data = (0..=255u8).cycle().take(4096).collect::<Vec<_>>();
+
+    for offset in 0..64 {
+        let mut input = Unstructured::new(&data[offset * 7..]);
+        let array = Array::<u16, 8>::arbitrary(&mut input).expect("Array");
+        assert!(array.len() <= array.capacity());
+
+        let queue = RingBuffer::<u16, 8>::arbitrary(&mut input).expect("RingBuffer");
+        assert!(queue.size() <= queue.capacity());
+    }
+
+    let mut input = Unstructured::new(&[]);
+    assert!(Array::<u16, 8>::arbitrary(&mut input).expect("Array").is_empty());
+    assert!(RingBuffer::<u16, 8>::arbitrary(&mut input).expect("RingBuffer").is_empty());
+}
@@ -161,3 +161,1522 @@ fn test_array() {
     assert!(array.pop().is_none());
 }
 
+
+#[test]
+fn test_array_zip_into() {
+    let mut left = Array::<usize, 8>::new();
+    let mut right = Array::<char, 4>::new();
+
+    for idx in 0..6 {
+        assert!(left.push(idx).is_none());
+    }
+    for ch in ['a', 'b', 'c'] {
+        assert!(right.push(ch).is_none());
+    }
+
+    let zipped: Array<(usize, char), 8> = left.clone().zip_into(right.clone());
+    assert_eq!(zipped.len(), 3);
+    assert_eq!(zipped, [(0, 'a'), (1, 'b'), (2, 'c')].as_ref());
+
+    let zipped: Array<(usize, char), 2> = left.zip_into(right);
+    assert_eq!(zipped.len(), 2);
+    assert_eq!(zipped, [(0, 'a'), (1, 'b')].as_ref());
+}
+
+#[test]
+fn test_array_try_map() {
+    static INPUT_COUNT: AtomicUsize = AtomicUsize::new(0);
+    static OUTPUT_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+    struct Input(usize);
+    impl Drop for Input {
+        fn drop(&mut self) {
+            INPUT_COUNT.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    struct Output(usize);
+    impl Drop for Output {
+        fn drop(&mut self) {
+            OUTPUT_COUNT.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let mut array = Array::<Input, 8>::new();
+    for idx in 0..6 {
+        assert!(array.push(Input(idx)).is_none());
+    }
+
+    let result = array.try_map(|input| Ok::<_, ()>(Output(input.0 * 2))).expect("To map");
+    assert_eq!(INPUT_COUNT.load(Ordering::Relaxed), 6);
+    assert_eq!(result.len(), 6);
+    for (idx, output) in result.iter().enumerate() {
+        assert_eq!(output.0, idx * 2);
+    }
+    drop(result);
+    assert_eq!(OUTPUT_COUNT.load(Ordering::Relaxed), 6);
+
+    let mut array = Array::<Input, 8>::new();
+    for idx in 0..6 {
+        assert!(array.push(Input(idx)).is_none());
+    }
+
+    let result = array.try_map(|input| match input.0 {
+        3 => Err(input.0),
+        value => Ok(Output(value)),
+    });
+    assert_eq!(result.err(), Some(3));
+    assert_eq!(INPUT_COUNT.load(Ordering::Relaxed), 12);
+    assert_eq!(OUTPUT_COUNT.load(Ordering::Relaxed), 9);
+}
+
+#[test]
+fn test_array_for_each_rblock() {
+    let mut array = Array::<usize, 16>::new();
+    for idx in 0..11 {
+        assert!(array.push(idx).is_none());
+    }
+
+    let mut blocks = Vec::new();
+    array.for_each_rblock(|block: &[usize; 4]| blocks.push(*block));
+    assert_eq!(blocks, [[7, 8, 9, 10], [3, 4, 5, 6]]);
+
+    let mut count = 0;
+    array.for_each_rblock(|_: &[usize; 12]| count += 1);
+    assert_eq!(count, 0);
+}
+
+#[test]
+fn test_array_unique_into() {
+    let mut array = Array::<u8, 16>::new();
+    for value in [3, 1, 3, 2, 1, 4, 2, 3, 5] {
+        assert!(array.push(value).is_none());
+    }
+
+    let unique: Array<u8, 16> = array.unique_into();
+    assert_eq!(unique, [3, 1, 2, 4, 5].as_ref());
+
+    let unique: Array<u8, 3> = array.unique_into();
+    assert_eq!(unique, [3, 1, 2].as_ref());
+    assert_eq!(array.len(), 9);
+}
+
+#[test]
+fn test_array_insert() {
+    let mut array = Array::<usize, 6>::new();
+
+    assert!(array.insert(0, 3).is_none());
+    assert!(array.insert(0, 1).is_none());
+    assert!(array.insert(array.len(), 5).is_none());
+    assert!(array.insert(1, 2).is_none());
+    assert!(array.insert(3, 4).is_none());
+    assert_eq!(array, [1, 2, 3, 4, 5].as_ref());
+
+    assert!(array.insert(0, 0).is_none());
+    assert_eq!(array, [0, 1, 2, 3, 4, 5].as_ref());
+
+    assert_eq!(array.insert(0, 10), Some(10));
+    assert_eq!(array.insert(array.len(), 10), Some(10));
+    assert_eq!(array, [0, 1, 2, 3, 4, 5].as_ref());
+}
+
+#[test]
+#[should_panic]
+fn test_array_insert_out_of_bounds() {
+    let mut array = Array::<usize, 6>::new();
+    assert!(array.push(1).is_none());
+    let _ = array.insert(2, 2);
+}
+
+#[test]
+fn test_array_remove() {
+    static COUNT: AtomicUsize = AtomicUsize::new(0);
+    #[derive(Debug, PartialEq)]
+    struct Lolka(usize);
+
+    impl Drop for Lolka {
+        fn drop(&mut self) {
+            COUNT.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let mut array = Array::<Lolka, 8>::new();
+    for idx in 0..6 {
+        assert!(array.push(Lolka(idx)).is_none());
+    }
+
+    assert_eq!(array.remove(0).0, 0);
+    assert_eq!(COUNT.load(Ordering::Relaxed), 1);
+    assert_eq!(array.remove(2).0, 3);
+    assert_eq!(COUNT.load(Ordering::Relaxed), 2);
+    assert_eq!(array.remove(array.len() - 1).0, 5);
+    assert_eq!(COUNT.load(Ordering::Relaxed), 3);
+
+    assert_eq!(array.len(), 3);
+    assert_eq!(array.iter().map(|elem| elem.0).collect::<Vec<_>>(), [1, 2, 4]);
+
+    drop(array);
+    assert_eq!(COUNT.load(Ordering::Relaxed), 6);
+}
+
+#[test]
+#[should_panic(expected = "removal index (is 1) should be < len (is 1)")]
+fn test_array_remove_out_of_bounds() {
+    let mut array = Array::<usize, 6>::new();
+    assert!(array.push(1).is_none());
+    array.remove(1);
+}
+
+#[test]
+fn test_array_from_iter() {
+    static COUNT: AtomicUsize = AtomicUsize::new(0);
+    struct Lolka(usize);
+
+    impl Drop for Lolka {
+        fn drop(&mut self) {
+            COUNT.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let array: Array<u32, 8> = (0..5).collect();
+    assert_eq!(array, [0, 1, 2, 3, 4].as_ref());
+
+    let array: Array<u32, 8> = (0..8).collect();
+    assert_eq!(array, [0, 1, 2, 3, 4, 5, 6, 7].as_ref());
+
+    let mut polled = 0;
+    let array: Array<u32, 8> = (0..100).inspect(|_| polled += 1).collect();
+    assert_eq!(array, [0, 1, 2, 3, 4, 5, 6, 7].as_ref());
+    assert_eq!(polled, 8);
+
+    let source = (0..12).map(Lolka).collect::<Vec<_>>();
+    let array: Array<Lolka, 8> = source.into_iter().collect();
+    assert_eq!(array.len(), 8);
+    assert_eq!(COUNT.load(Ordering::Relaxed), 4);
+    for (idx, elem) in array.iter().enumerate() {
+        assert_eq!(elem.0, idx);
+    }
+
+    drop(array);
+    assert_eq!(COUNT.load(Ordering::Relaxed), 12);
+}
+
+#[test]
+fn test_array_extend() {
+    static COUNT: AtomicUsize = AtomicUsize::new(0);
+    struct Lolka(usize);
+
+    impl Drop for Lolka {
+        fn drop(&mut self) {
+            COUNT.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let mut array = Array::<Lolka, 8>::new();
+    for idx in 0..3 {
+        assert!(array.push(Lolka(idx)).is_none());
+    }
+
+    let mut source = (3..12).map(Lolka).collect::<Vec<_>>().into_iter();
+    array.extend(source.by_ref());
+    assert_eq!(array.len(), array.capacity());
+    assert_eq!(COUNT.load(Ordering::Relaxed), 0);
+    for (idx, elem) in array.iter().enumerate() {
+        assert_eq!(elem.0, idx);
+    }
+
+    assert_eq!(source.len(), 4);
+    assert_eq!(source.next().map(|elem| elem.0), Some(8));
+    assert_eq!(COUNT.load(Ordering::Relaxed), 1);
+
+    array.extend(source);
+    assert_eq!(array.len(), array.capacity());
+    assert_eq!(COUNT.load(Ordering::Relaxed), 4);
+
+    drop(array);
+    assert_eq!(COUNT.load(Ordering::Relaxed), 12);
+}
+
+#[test]
+fn test_array_extend_from_slice() {
+    let mut array = Array::<u8, 8>::new();
+
+    assert_eq!(array.extend_from_slice(b"abc"), 3);
+    assert_eq!(array.extend_from_slice(b""), 0);
+    assert_eq!(array.extend_from_slice(b"defgh"), 5);
+    assert_eq!(array, b"abcdefgh".as_ref());
+
+    array.truncate(6);
+    assert_eq!(array.extend_from_slice(b"1234"), 2);
+    assert_eq!(array, b"abcdef12".as_ref());
+    assert_eq!(array.extend_from_slice(b"1234"), 0);
+    assert_eq!(array, b"abcdef12".as_ref());
+}
+
+#[test]
+fn test_array_capacity_error() {
+    use statiki::CapacityError;
+
+    fn fill<const C: usize>(array: &mut Array<usize, C>, count: usize) -> Result<(), CapacityError<usize>> {
+        for idx in 0..count {
+            array.try_push(idx)?;
+        }
+        Ok(())
+    }
+
+    let mut array = Array::<usize, 4>::new();
+    assert_eq!(fill(&mut array, 3), Ok(()));
+    assert_eq!(fill(&mut array, 3), Err(CapacityError(1)));
+    assert_eq!(array, [0, 1, 2, 0].as_ref());
+    assert_eq!(CapacityError(1).to_string(), "insufficient capacity");
+
+    assert_eq!(array.try_insert(0, 10), Err(CapacityError(10)));
+    array.clear();
+    assert_eq!(array.try_insert(0, 10), Ok(()));
+    assert_eq!(array.try_resize(5, 1), Err(CapacityError(1)));
+    assert_eq!(array, [10].as_ref());
+    assert_eq!(array.try_resize(4, 1), Ok(()));
+    assert_eq!(array, [10, 1, 1, 1].as_ref());
+}
+
+#[test]
+fn test_array_is_full() {
+    let mut array = Array::<usize, 4>::new();
+    assert!(!array.is_full());
+    assert_eq!(array.remaining_capacity(), 4);
+
+    assert!(array.push(1).is_none());
+    assert!(!array.is_full());
+    assert_eq!(array.remaining_capacity(), 3);
+
+    array.resize(array.capacity(), 0);
+    assert!(array.is_full());
+    assert_eq!(array.remaining_capacity(), 0);
+
+    array.pop();
+    assert!(!array.is_full());
+    assert_eq!(array.remaining_capacity(), 1);
+}
+
+#[test]
+fn test_array_clone_non_copy() {
+    static CLONES: AtomicUsize = AtomicUsize::new(0);
+    static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+    struct Lolka(String);
+
+    impl Clone for Lolka {
+        fn clone(&self) -> Self {
+            CLONES.fetch_add(1, Ordering::Relaxed);
+            Lolka(self.0.clone())
+        }
+    }
+
+    impl Drop for Lolka {
+        fn drop(&mut self) {
+            DROPS.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let mut array = Array::<Lolka, 16>::new();
+    for idx in 0..5 {
+        assert!(array.push(Lolka(idx.to_string())).is_none());
+    }
+
+    let cloned = array.clone();
+    assert_eq!(CLONES.load(Ordering::Relaxed), array.len());
+    assert_eq!(DROPS.load(Ordering::Relaxed), 0);
+    assert_eq!(cloned.len(), array.len());
+
+    drop(array);
+    assert_eq!(DROPS.load(Ordering::Relaxed), 5);
+    for (idx, elem) in cloned.iter().enumerate() {
+        assert_eq!(elem.0, idx.to_string());
+    }
+
+    drop(cloned);
+    assert_eq!(DROPS.load(Ordering::Relaxed), 10);
+}
+
+#[test]
+fn test_array_get() {
+    let mut array = Array::<usize, 4>::new();
+    assert!(array.get(0).is_none());
+    assert!(array.get_mut(0).is_none());
+
+    assert!(array.push(1).is_none());
+    assert!(array.push(2).is_none());
+    assert_eq!(array.get(0), Some(&1));
+    assert_eq!(array.get(1), Some(&2));
+    assert!(array.get(2).is_none());
+    assert!(array.get(4).is_none());
+
+    *array.get_mut(1).expect("To get value") = 20;
+    assert_eq!(array.get(1), Some(&20));
+    assert!(array.get_mut(2).is_none());
+}
+
+#[test]
+fn test_array_index() {
+    let mut array = Array::<usize, 8>::new();
+    for idx in 0..5 {
+        assert!(array.push(idx).is_none());
+    }
+
+    assert_eq!(array[0], 0);
+    assert_eq!(array[4], 4);
+    array[4] = 40;
+    assert_eq!(array[4], 40);
+
+    assert_eq!(&array[1..3], &[1, 2]);
+    assert_eq!(&array[3..], &[3, 40]);
+    assert_eq!(&array[..2], &[0, 1]);
+    assert_eq!(&array[..], &[0, 1, 2, 3, 40]);
+    assert_eq!(&array[1..=2], &[1, 2]);
+    assert_eq!(&array[..=1], &[0, 1]);
+    assert_eq!(&array[5..], &[]);
+
+    array[..2].copy_from_slice(&[10, 11]);
+    assert_eq!(array, [10, 11, 2, 3, 40].as_ref());
+}
+
+#[test]
+#[should_panic(expected = "index 5 is out of bounds of Array with len 5")]
+fn test_array_index_out_of_bounds() {
+    let mut array = Array::<usize, 8>::new();
+    array.resize(5, 0);
+    let _ = array[5];
+}
+
+#[test]
+#[should_panic(expected = "range 2..6 is out of bounds of Array with len 5")]
+fn test_array_range_index_out_of_bounds() {
+    let mut array = Array::<usize, 8>::new();
+    array.resize(5, 0);
+    let _ = &array[2..6];
+}
+
+#[test]
+#[should_panic(expected = "range 3..2 is out of bounds of Array with len 5")]
+fn test_array_range_index_reversed() {
+    let mut array = Array::<usize, 8>::new();
+    array.resize(5, 0);
+    #[allow(clippy::reversed_empty_ranges)]
+    let _ = &mut array[3..2];
+}
+
+#[test]
+fn test_array_hash() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    fn hash<T: Hash + ?Sized>(value: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    let mut array = Array::<u8, 16>::new();
+    assert_eq!(hash(&array), hash(&[0u8; 0][..]));
+
+    assert_eq!(array.extend_from_slice(b"statiki"), 7);
+    assert_eq!(hash(&array), hash(&b"statiki"[..]));
+    assert_ne!(hash(&array), hash(&b"statik"[..]));
+}
+
+#[test]
+fn test_array_ord() {
+    use core::cmp::Ordering;
+
+    let cases: [(&[u32], &[u32]); 5] = [
+        (&[], &[]),
+        (&[], &[1]),
+        (&[1, 2], &[1, 2, 0]),
+        (&[1, 3], &[1, 2, 5]),
+        (&[2], &[1, 9, 9]),
+    ];
+
+    for (left, right) in cases {
+        let mut left_array = Array::<u32, 4>::new();
+        assert_eq!(left_array.extend_from_slice(left), left.len());
+        let mut right_array = Array::<u32, 4>::new();
+        assert_eq!(right_array.extend_from_slice(right), right.len());
+
+        let expected = left.to_vec().cmp(&right.to_vec());
+        assert_eq!(left_array.cmp(&right_array), expected);
+        assert_eq!(right_array.cmp(&left_array), expected.reverse());
+        assert_eq!(left_array.partial_cmp(&right_array), Some(expected));
+    }
+
+    let mut floats = Array::<f32, 2>::new();
+    assert!(floats.push(f32::NAN).is_none());
+    assert_eq!(floats.partial_cmp(&floats.clone()), None);
+    assert_eq!(Array::<f32, 2>::new().partial_cmp(&floats), Some(Ordering::Less));
+}
+
+#[test]
+fn test_array_retain() {
+    static COUNT: AtomicUsize = AtomicUsize::new(0);
+    struct Lolka(usize);
+
+    impl Drop for Lolka {
+        fn drop(&mut self) {
+            COUNT.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let mut array = Array::<Lolka, 16>::new();
+    for idx in 0..10 {
+        assert!(array.push(Lolka(idx)).is_none());
+    }
+
+    array.retain(|elem| elem.0 % 3 != 0);
+    assert_eq!(COUNT.load(Ordering::Relaxed), 4);
+    assert_eq!(array.iter().map(|elem| elem.0).collect::<Vec<_>>(), [1, 2, 4, 5, 7, 8]);
+
+    array.retain_mut(|elem| {
+        elem.0 *= 10;
+        elem.0 != 40
+    });
+    assert_eq!(COUNT.load(Ordering::Relaxed), 5);
+    assert_eq!(array.iter().map(|elem| elem.0).collect::<Vec<_>>(), [10, 20, 50, 70, 80]);
+
+    array.retain(|_| false);
+    assert!(array.is_empty());
+    assert_eq!(COUNT.load(Ordering::Relaxed), 10);
+}
+
+#[test]
+fn test_array_retain_panic() {
+    static COUNT: AtomicUsize = AtomicUsize::new(0);
+    struct Lolka(usize);
+
+    impl Drop for Lolka {
+        fn drop(&mut self) {
+            COUNT.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let mut array = Array::<Lolka, 16>::new();
+    for idx in 0..10 {
+        assert!(array.push(Lolka(idx)).is_none());
+    }
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        array.retain(|elem| match elem.0 {
+            6 => panic!("Lolka"),
+            value => value % 2 == 0,
+        })
+    }));
+    assert!(result.is_err());
+    assert_eq!(COUNT.load(Ordering::Relaxed), 3);
+    assert_eq!(array.iter().map(|elem| elem.0).collect::<Vec<_>>(), [0, 2, 4, 6, 7, 8, 9]);
+
+    drop(array);
+    assert_eq!(COUNT.load(Ordering::Relaxed), 10);
+}
+
+#[test]
+fn test_array_drain() {
+    static COUNT: AtomicUsize = AtomicUsize::new(0);
+    struct Lolka(usize);
+
+    impl Drop for Lolka {
+        fn drop(&mut self) {
+            COUNT.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let mut array = Array::<Lolka, 16>::new();
+    for idx in 0..10 {
+        assert!(array.push(Lolka(idx)).is_none());
+    }
+
+    let mut drain = array.drain(2..6);
+    assert_eq!(drain.len(), 4);
+    assert_eq!(drain.next().map(|elem| elem.0), Some(2));
+    assert_eq!(drain.next_back().map(|elem| elem.0), Some(5));
+    assert_eq!(drain.len(), 2);
+    drop(drain);
+    assert_eq!(COUNT.load(Ordering::Relaxed), 4);
+    assert_eq!(array.iter().map(|elem| elem.0).collect::<Vec<_>>(), [0, 1, 6, 7, 8, 9]);
+
+    let drain = array.drain(3..3);
+    assert_eq!(drain.len(), 0);
+    drop(drain);
+    assert_eq!(COUNT.load(Ordering::Relaxed), 4);
+    assert_eq!(array.len(), 6);
+
+    let drained = array.drain(..).map(|elem| elem.0).collect::<Vec<_>>();
+    assert_eq!(drained, [0, 1, 6, 7, 8, 9]);
+    assert!(array.is_empty());
+    assert_eq!(COUNT.load(Ordering::Relaxed), 10);
+
+    for idx in 0..4 {
+        assert!(array.push(Lolka(idx)).is_none());
+    }
+    core::mem::forget(array.drain(1..3));
+    assert_eq!(array.len(), 1);
+    drop(array);
+    assert_eq!(COUNT.load(Ordering::Relaxed), 11);
+}
+
+#[test]
+#[should_panic(expected = "range 2..5 is out of bounds of Array with len 4")]
+fn test_array_drain_out_of_bounds() {
+    let mut array = Array::<usize, 8>::new();
+    array.resize(4, 0);
+    array.drain(2..5);
+}
+
+#[test]
+fn test_array_from_slice() {
+    use core::convert::TryFrom;
+
+    let array = Array::<u8, 4>::from_slice(b"ab").expect("To fit");
+    assert_eq!(array, b"ab".as_ref());
+    let array = Array::<u8, 4>::from_slice(b"abcd").expect("To fit");
+    assert_eq!(array, b"abcd".as_ref());
+    assert!(Array::<u8, 4>::from_slice(b"abcde").is_none());
+
+    let array = Array::<u8, 4>::try_from(b"abc".as_ref()).expect("To fit");
+    assert_eq!(array, b"abc".as_ref());
+    let array = Array::<u8, 4>::try_from(b"abcd".as_ref()).expect("To fit");
+    assert!(array.is_full());
+    let error = Array::<u8, 4>::try_from(b"abcdef".as_ref()).unwrap_err();
+    assert_eq!(error.0.len(), 6);
+}
+
+#[test]
+fn test_array_into_array() {
+    static COUNT: AtomicUsize = AtomicUsize::new(0);
+    struct Lolka(usize);
+
+    impl Drop for Lolka {
+        fn drop(&mut self) {
+            COUNT.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let array = Array::from([Lolka(0), Lolka(1), Lolka(2)]);
+    assert_eq!(array.len(), 3);
+    let data = array.into_array().map_err(|_| ()).expect("To be full");
+    assert_eq!(COUNT.load(Ordering::Relaxed), 0);
+    assert_eq!(data[2].0, 2);
+    drop(data);
+    assert_eq!(COUNT.load(Ordering::Relaxed), 3);
+
+    let mut array = Array::<Lolka, 3>::new();
+    assert!(array.push(Lolka(0)).is_none());
+    assert!(array.push(Lolka(1)).is_none());
+    let array = match array.into_array() {
+        Ok(_) => panic!("Should not be full"),
+        Err(array) => array,
+    };
+    assert_eq!(COUNT.load(Ordering::Relaxed), 3);
+    assert_eq!(array.iter().map(|elem| elem.0).collect::<Vec<_>>(), [0, 1]);
+    drop(array);
+    assert_eq!(COUNT.load(Ordering::Relaxed), 5);
+}
+
+#[test]
+fn test_array_as_full_array() {
+    let mut array = Array::<u8, 4>::new();
+    assert!(array.as_full_array().is_none());
+
+    array.extend_from_slice(b"abc");
+    assert!(array.as_full_array().is_none());
+    assert!(array.as_full_array_mut().is_none());
+
+    assert!(array.push(b'd').is_none());
+    assert_eq!(array.as_full_array(), Some(b"abcd"));
+    array.as_full_array_mut().expect("To be full")[0] = b'z';
+    assert_eq!(array, b"zbcd".as_ref());
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn test_array_bytemuck_cast() {
+    let mut array = Array::<u8, 4>::zeroed();
+    assert!(array.is_full());
+    assert_eq!(array.cast_full::<u32>(), Some(&0));
+    assert!(array.cast_full::<u16>().is_none());
+
+    *array.cast_full_mut::<[u8; 4]>().expect("To cast") = *b"abcd";
+    assert_eq!(array.cast_full::<[u8; 4]>(), Some(b"abcd"));
+
+    array.pop();
+    assert!(array.cast_full::<[u8; 4]>().is_none());
+    assert!(array.cast_full_mut::<[u8; 4]>().is_none());
+
+    let array = Array::from([1u16, 2]);
+    let bytes = array.cast_full::<[u8; 4]>().expect("To cast");
+    assert_eq!(bytes[..2], 1u16.to_ne_bytes());
+    assert_eq!(bytes[2..], 2u16.to_ne_bytes());
+}
+
+#[test]
+fn test_array_default() {
+    #[derive(Default)]
+    struct Packet {
+        payload: Array<u8, 4>,
+    }
+
+    let array = Array::<u8, 4>::default();
+    assert!(array.is_empty());
+    assert_eq!(array.capacity(), 4);
+
+    let packet = Packet::default();
+    assert!(packet.payload.is_empty());
+    assert_eq!(packet.payload.capacity(), 4);
+}
+
+#[test]
+fn test_array_macro() {
+    let array = statiki::array![1u32, 2, 3, 4, 5];
+    assert_eq!(array.len(), 5);
+    assert_eq!(array.capacity(), 5);
+    assert_eq!(array, [1, 2, 3, 4, 5].as_ref());
+
+    let array = statiki::array![String::from("lolka"); 3];
+    assert_eq!(array.len(), 3);
+    assert!(array.iter().all(|elem| elem == "lolka"));
+
+    let array: Array<u8, 0> = statiki::array![];
+    assert!(array.is_empty());
+
+    const LEN: usize = 2;
+    let array = statiki::array![7u8; LEN + 2];
+    assert_eq!(array.capacity(), 4);
+    assert_eq!(array, [7, 7, 7, 7]);
+
+    let array = statiki::array![String::new(); 0];
+    assert!(array.is_empty());
+}
+
+#[test]
+fn test_array_ref_iterator() {
+    fn sum<'a, I: IntoIterator<Item = &'a u32>>(iter: I) -> u32 {
+        iter.into_iter().sum()
+    }
+
+    let mut array = Array::<u32, 8>::new();
+    for idx in 1..=4 {
+        assert!(array.push(idx).is_none());
+    }
+
+    assert_eq!(sum(&array), 10);
+
+    for elem in &mut array {
+        *elem *= 2;
+    }
+
+    let mut expected = 2;
+    for elem in &array {
+        assert_eq!(*elem, expected);
+        expected += 2;
+    }
+    assert_eq!(sum(&array), 20);
+}
+
+#[test]
+fn test_array_iterator_double_ended() {
+    static COUNT: AtomicUsize = AtomicUsize::new(0);
+    struct Lolka(usize);
+
+    impl Drop for Lolka {
+        fn drop(&mut self) {
+            COUNT.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let mut array = Array::<Lolka, 8>::new();
+    for idx in 0..7 {
+        assert!(array.push(Lolka(idx)).is_none());
+    }
+
+    let mut iter = array.into_iter();
+    assert_eq!(iter.len(), 7);
+    let mut front = Vec::new();
+    let mut back = Vec::new();
+    loop {
+        match iter.next() {
+            Some(elem) => front.push(elem.0),
+            None => break,
+        }
+        assert_eq!(iter.len(), 7 - front.len() - back.len());
+        match iter.next_back() {
+            Some(elem) => back.push(elem.0),
+            None => break,
+        }
+        assert_eq!(iter.len(), 7 - front.len() - back.len());
+    }
+    assert_eq!(front, [0, 1, 2, 3]);
+    assert_eq!(back, [6, 5, 4]);
+    assert!(iter.next_back().is_none());
+    assert_eq!(COUNT.load(Ordering::Relaxed), 7);
+    drop(iter);
+    assert_eq!(COUNT.load(Ordering::Relaxed), 7);
+
+    let mut array = Array::<Lolka, 8>::new();
+    for idx in 0..7 {
+        assert!(array.push(Lolka(idx)).is_none());
+    }
+    let mut iter = array.into_iter();
+    assert_eq!(iter.next_back().map(|elem| elem.0), Some(6));
+    assert_eq!(iter.next().map(|elem| elem.0), Some(0));
+    drop(iter);
+    assert_eq!(COUNT.load(Ordering::Relaxed), 14);
+}
+
+#[test]
+fn test_array_spare_capacity_mut() {
+    let mut array = Array::<u32, 8>::new();
+    assert!(array.push(1).is_none());
+    assert_eq!(array.spare_capacity_mut().len(), 7);
+
+    let spare = array.spare_capacity_mut();
+    for (idx, elem) in spare.iter_mut().take(3).enumerate() {
+        elem.write(idx as u32 + 10);
+    }
+    unsafe {
+        array.set_len(4);
+    }
+
+    assert_eq!(array, [1, 10, 11, 12].as_ref());
+    assert_eq!(array.spare_capacity_mut().len(), 4);
+
+    array.resize(8, 0);
+    assert!(array.spare_capacity_mut().is_empty());
+}
+
+#[test]
+fn test_array_fill() {
+    let mut array = Array::<u32, 8>::new();
+    array.fill(1);
+    assert!(array.is_empty());
+
+    for idx in 0..5 {
+        assert!(array.push(idx).is_none());
+    }
+
+    array.fill(7);
+    assert_eq!(array, [7, 7, 7, 7, 7].as_ref());
+
+    let mut counter = 0;
+    array.fill_with(|| {
+        counter += 1;
+        counter
+    });
+    assert_eq!(array, [1, 2, 3, 4, 5].as_ref());
+    assert_eq!(counter, 5);
+}
+
+#[test]
+fn test_array_sort_unstable() {
+    let mut array = Array::<u32, 8>::new();
+    array.extend_from_slice(&[5, 1, 4, 2, 3]);
+
+    array.sort_unstable();
+    assert_eq!(array, [1, 2, 3, 4, 5].as_ref());
+    assert_eq!(array.len(), 5);
+
+    array.sort_unstable_by(|left, right| right.cmp(left));
+    assert_eq!(array, [5, 4, 3, 2, 1].as_ref());
+
+    //Spare capacity is not touched
+    array.truncate(2);
+    array.sort_unstable();
+    assert_eq!(array, [4, 5].as_ref());
+    assert!(array.push(0).is_none());
+    assert_eq!(array, [4, 5, 0].as_ref());
+}
+
+#[test]
+fn test_array_swap() {
+    let mut array = Array::<String, 8>::new();
+    for text in ["a", "b", "c"] {
+        assert!(array.push(text.to_owned()).is_none());
+    }
+
+    array.swap(0, 2);
+    assert_eq!(array, ["c", "b", "a"].map(String::from).as_ref());
+
+    array.swap(1, 1);
+    assert_eq!(array, ["c", "b", "a"].map(String::from).as_ref());
+}
+
+#[test]
+#[should_panic(expected = "index 3 is out of bounds of Array with len 3")]
+fn test_array_swap_out_of_bounds() {
+    let mut array = Array::<u32, 8>::new();
+    array.extend_from_slice(&[1, 2, 3]);
+    array.swap(0, 3);
+}
+
+#[test]
+fn test_array_rotate() {
+    let mut array = Array::<u32, 8>::new();
+    array.extend_from_slice(&[1, 2, 3, 4, 5]);
+    let mut expected = [1, 2, 3, 4, 5];
+
+    array.rotate_left(2);
+    expected.rotate_left(2);
+    assert_eq!(array, expected.as_ref());
+
+    array.rotate_right(2);
+    expected.rotate_right(2);
+    assert_eq!(array, expected.as_ref());
+    assert_eq!(array, [1, 2, 3, 4, 5].as_ref());
+
+    array.rotate_left(0);
+    array.rotate_left(5);
+    array.rotate_right(0);
+    array.rotate_right(5);
+    assert_eq!(array, [1, 2, 3, 4, 5].as_ref());
+}
+
+#[test]
+#[should_panic(expected = "rotation (is 6) should be <= len (is 5)")]
+fn test_array_rotate_out_of_bounds() {
+    let mut array = Array::<u32, 8>::new();
+    array.extend_from_slice(&[1, 2, 3, 4, 5]);
+    array.rotate_left(6);
+}
+
+#[test]
+fn test_array_truncate_drop_order() {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    struct Lolka(usize, Rc<RefCell<Vec<usize>>>);
+
+    impl Drop for Lolka {
+        fn drop(&mut self) {
+            self.1.borrow_mut().push(self.0);
+        }
+    }
+
+    let order = Rc::new(RefCell::new(Vec::new()));
+    let mut array = Array::<Lolka, 8>::new();
+    for idx in 0..6 {
+        assert!(array.push(Lolka(idx, order.clone())).is_none());
+    }
+
+    array.truncate(3);
+    assert_eq!(array.len(), 3);
+    assert_eq!(*order.borrow(), [3, 4, 5]);
+
+    drop(array);
+    assert_eq!(*order.borrow(), [3, 4, 5, 0, 1, 2]);
+}
+
+#[test]
+fn test_array_split_off() {
+    static COUNT: AtomicUsize = AtomicUsize::new(0);
+    struct Lolka(usize);
+
+    impl Drop for Lolka {
+        fn drop(&mut self) {
+            COUNT.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let mut array = Array::<Lolka, 8>::new();
+    for idx in 0..5 {
+        assert!(array.push(Lolka(idx)).is_none());
+    }
+
+    let tail = array.split_off(2);
+    assert_eq!(COUNT.load(Ordering::Relaxed), 0);
+    assert_eq!(array.iter().map(|elem| elem.0).collect::<Vec<_>>(), [0, 1]);
+    assert_eq!(tail.iter().map(|elem| elem.0).collect::<Vec<_>>(), [2, 3, 4]);
+
+    let empty = array.split_off(2);
+    assert!(empty.is_empty());
+    assert_eq!(array.len(), 2);
+
+    drop(tail);
+    assert_eq!(COUNT.load(Ordering::Relaxed), 3);
+    let all = array.split_off(0);
+    assert!(array.is_empty());
+    drop(all);
+    assert_eq!(COUNT.load(Ordering::Relaxed), 5);
+}
+
+#[test]
+#[should_panic(expected = "split index (is 3) should be <= len (is 2)")]
+fn test_array_split_off_out_of_bounds() {
+    let mut array = Array::<u32, 8>::new();
+    array.extend_from_slice(&[1, 2]);
+    array.split_off(3);
+}
+
+#[test]
+fn test_array_contains_position() {
+    let mut array = Array::<u32, 8>::new();
+    assert!(!array.contains(&0));
+    assert_eq!(array.position(|_| true), None);
+
+    array.extend_from_slice(&[5, 7, 9, 7]);
+    assert!(array.contains(&9));
+    assert!(!array.contains(&0));
+    assert_eq!(array.position(|elem| *elem == 7), Some(1));
+    assert_eq!(array.position(|elem| *elem > 8), Some(2));
+    assert_eq!(array.position(|elem| *elem == 0), None);
+
+    //Spare capacity is not searched
+    array.pop();
+    array.pop();
+    assert!(!array.contains(&9));
+}
+
+#[test]
+fn test_array_first_last() {
+    let mut array = Array::<u32, 8>::new();
+    assert!(array.first().is_none());
+    assert!(array.last().is_none());
+    assert!(array.first_mut().is_none());
+    assert!(array.last_mut().is_none());
+
+    assert!(array.push(1).is_none());
+    assert_eq!(array.first(), Some(&1));
+    assert_eq!(array.last(), Some(&1));
+
+    array.extend_from_slice(&[2, 3]);
+    assert_eq!(array.first(), Some(&1));
+    assert_eq!(array.last(), Some(&3));
+
+    *array.last_mut().expect("Not empty") += 10;
+    *array.first_mut().expect("Not empty") = 0;
+    assert_eq!(array, [0, 2, 13].as_ref());
+    assert_eq!(array.pop(), Some(13));
+    assert_eq!(array.last(), Some(&2));
+}
+
+#[test]
+fn test_array_map() {
+    let mut array = Array::<u32, 8>::new();
+    array.extend_from_slice(&[1, 2, 3]);
+
+    let array = array.map(|elem| format!("{}", elem * 2));
+    assert_eq!(array.len(), 3);
+    assert_eq!(array, ["2", "4", "6"].map(String::from).as_ref());
+}
+
+#[test]
+fn test_array_map_panic() {
+    static COUNT: AtomicUsize = AtomicUsize::new(0);
+    struct Lolka(usize);
+
+    impl Drop for Lolka {
+        fn drop(&mut self) {
+            COUNT.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let mut array = Array::<usize, 8>::new();
+    array.extend_from_slice(&[0, 1, 2, 3, 4]);
+    let array = array.map(Lolka);
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(move || {
+        array.map(|elem| match elem.0 {
+            2 => panic!("lolka"),
+            idx => Lolka(idx),
+        })
+    }));
+    assert!(result.is_err());
+    //Consumed 3 inputs, 2 mapped outputs and 2 remaining inputs
+    assert_eq!(COUNT.load(Ordering::Relaxed), 7);
+}
+
+#[test]
+fn test_array_extend_from_within() {
+    let mut array = Array::<String, 8>::new();
+    for text in ["a", "b", "c"] {
+        assert!(array.push(text.to_owned()).is_none());
+    }
+
+    assert_eq!(array.extend_from_within(1..), 2);
+    assert_eq!(array, ["a", "b", "c", "b", "c"].map(String::from).as_ref());
+
+    //Stops at capacity
+    assert_eq!(array.extend_from_within(..=3), 3);
+    assert_eq!(array, ["a", "b", "c", "b", "c", "a", "b", "c"].map(String::from).as_ref());
+    assert_eq!(array.extend_from_within(..), 0);
+    assert!(array.is_full());
+}
+
+#[test]
+#[should_panic(expected = "range 2..4 is out of bounds of Array with len 3")]
+fn test_array_extend_from_within_out_of_bounds() {
+    let mut array = Array::<u32, 8>::new();
+    array.extend_from_slice(&[1, 2, 3]);
+    array.extend_from_within(2..4);
+}
+
+#[test]
+fn test_array_as_chunks() {
+    let mut array = Array::<u32, 8>::new();
+    let (chunks, remainder) = array.as_chunks::<2>();
+    assert!(chunks.is_empty());
+    assert!(remainder.is_empty());
+
+    array.extend_from_slice(&[1, 2, 3, 4, 5, 6]);
+    let (chunks, remainder) = array.as_chunks::<3>();
+    assert_eq!(chunks, [[1, 2, 3], [4, 5, 6]]);
+    assert!(remainder.is_empty());
+    let (remainder, chunks) = array.as_rchunks::<3>();
+    assert!(remainder.is_empty());
+    assert_eq!(chunks, [[1, 2, 3], [4, 5, 6]]);
+
+    assert!(array.push(7).is_none());
+    let (chunks, remainder) = array.as_chunks::<2>();
+    assert_eq!(chunks, [[1, 2], [3, 4], [5, 6]]);
+    assert_eq!(remainder, [7]);
+    let (remainder, chunks) = array.as_rchunks::<2>();
+    assert_eq!(remainder, [1]);
+    assert_eq!(chunks, [[2, 3], [4, 5], [6, 7]]);
+
+    let (chunks, remainder) = array.as_chunks::<8>();
+    assert!(chunks.is_empty());
+    assert_eq!(remainder, [1, 2, 3, 4, 5, 6, 7]);
+}
+
+#[test]
+fn test_array_try_from_iter() {
+    let array = Array::<u32, 4>::try_from_iter(0..4).expect("Exact fit");
+    assert_eq!(array, [0, 1, 2, 3].as_ref());
+
+    let array = Array::<u32, 4>::try_from_iter(0..2).expect("Under fill");
+    assert_eq!(array, [0, 1].as_ref());
+
+    let error = Array::<u32, 4>::try_from_iter(0..10).unwrap_err();
+    assert_eq!(error, statiki::CapacityError(4));
+}
+
+#[test]
+fn test_array_eq_fixed_array() {
+    let mut array = Array::<u8, 16>::new();
+    assert_eq!(array, []);
+    assert_eq!([], array);
+
+    array.extend_from_slice(b"abcd");
+    assert_eq!(array, *b"abcd");
+    assert_eq!(*b"abcd", array);
+    assert_ne!(array, *b"abce");
+    assert_ne!(array, *b"abc");
+    assert_ne!(*b"abcde", array);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_array_to_vec() {
+    static COUNT: AtomicUsize = AtomicUsize::new(0);
+    struct Lolka(usize);
+
+    impl Drop for Lolka {
+        fn drop(&mut self) {
+            COUNT.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let mut array = Array::<u32, 8>::new();
+    assert!(array.to_vec().is_empty());
+    array.extend_from_slice(&[1, 2, 3]);
+    assert_eq!(array.to_vec(), [1, 2, 3]);
+    assert_eq!(Vec::from(array), [1, 2, 3]);
+
+    let mut array = Array::<Lolka, 8>::new();
+    for idx in 0..3 {
+        assert!(array.push(Lolka(idx)).is_none());
+    }
+
+    let vec = Vec::from(array);
+    assert_eq!(COUNT.load(Ordering::Relaxed), 0);
+    assert_eq!(vec.len(), 3);
+    assert_eq!(vec.iter().map(|elem| elem.0).collect::<Vec<_>>(), [0, 1, 2]);
+    drop(vec);
+    assert_eq!(COUNT.load(Ordering::Relaxed), 3);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_array_try_from_vec() {
+    use core::convert::TryFrom;
+
+    static COUNT: AtomicUsize = AtomicUsize::new(0);
+    struct Lolka(usize);
+
+    impl Drop for Lolka {
+        fn drop(&mut self) {
+            COUNT.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let array = Array::<Lolka, 4>::try_from((0..4).map(Lolka).collect::<Vec<_>>()).map_err(|_| ()).expect("To fit");
+    assert_eq!(COUNT.load(Ordering::Relaxed), 0);
+    assert_eq!(array.iter().map(|elem| elem.0).collect::<Vec<_>>(), [0, 1, 2, 3]);
+    drop(array);
+    assert_eq!(COUNT.load(Ordering::Relaxed), 4);
+
+    let error = Array::<Lolka, 4>::try_from((0..5).map(Lolka).collect::<Vec<_>>()).map(|_| ()).unwrap_err();
+    assert_eq!(COUNT.load(Ordering::Relaxed), 4);
+    assert_eq!(error.0.len(), 5);
+    drop(error);
+    assert_eq!(COUNT.load(Ordering::Relaxed), 9);
+
+    let array = Array::<u32, 4>::try_from(vec![]).expect("To fit");
+    assert!(array.is_empty());
+}
+
+#[test]
+fn test_array_resize_with() {
+    static COUNT: AtomicUsize = AtomicUsize::new(0);
+    struct Lolka(usize);
+
+    impl Drop for Lolka {
+        fn drop(&mut self) {
+            COUNT.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let mut array = Array::<Lolka, 8>::new();
+    let mut counter = 0;
+    array.resize_with(5, || {
+        counter += 1;
+        Lolka(counter)
+    });
+    assert_eq!(counter, 5);
+    assert_eq!(array.iter().map(|elem| elem.0).collect::<Vec<_>>(), [1, 2, 3, 4, 5]);
+
+    array.resize_with(2, || unreachable!());
+    assert_eq!(COUNT.load(Ordering::Relaxed), 3);
+    assert_eq!(array.iter().map(|elem| elem.0).collect::<Vec<_>>(), [1, 2]);
+
+    array.resize_with(2, || unreachable!());
+    assert_eq!(array.len(), 2);
+}
+
+#[test]
+#[should_panic]
+fn test_array_resize_with_overflow() {
+    let mut array = Array::<u32, 4>::new();
+    array.resize_with(5, || 0);
+}
+
+#[test]
+fn test_array_split_first_last() {
+    let mut array = Array::<u32, 8>::new();
+    assert!(array.split_first().is_none());
+    assert!(array.split_last().is_none());
+
+    assert!(array.push(1).is_none());
+    assert_eq!(array.split_first(), Some((&1, [].as_ref())));
+    assert_eq!(array.split_last(), Some((&1, [].as_ref())));
+
+    array.extend_from_slice(&[2, 3]);
+    assert_eq!(array.split_first(), Some((&1, [2, 3].as_ref())));
+    assert_eq!(array.split_last(), Some((&3, [1, 2].as_ref())));
+}
+
+#[test]
+fn test_array_get_many_mut() {
+    let mut array = Array::<u32, 8>::new();
+    array.extend_from_slice(&[1, 2, 3, 4]);
+
+    let [first, last] = array.get_many_mut([0, 3]).expect("Disjoint");
+    core::mem::swap(first, last);
+    assert_eq!(array, [4, 2, 3, 1]);
+
+    let [a, b, c] = array.get_many_mut([2, 0, 1]).expect("Disjoint");
+    *a += 10;
+    *b += 20;
+    *c += 30;
+    assert_eq!(array, [24, 32, 13, 1]);
+
+    assert!(array.get_many_mut([1, 2, 1]).is_none());
+    assert!(array.get_many_mut([0, 4]).is_none());
+    assert!(array.get_many_mut([5]).is_none());
+    assert!(array.get_many_mut([]).is_some());
+}
+
+#[test]
+fn test_array_iter() {
+    fn sum<const C: usize>(array: &Array<u32, C>) -> u32 {
+        array.iter().sum()
+    }
+
+    let mut array = Array::<u32, 8>::new();
+    assert_eq!(sum(&array), 0);
+    array.extend_from_slice(&[1, 2, 3]);
+    assert_eq!(sum(&array), 6);
+
+    for elem in array.iter_mut() {
+        *elem *= 2;
+    }
+    assert_eq!(array, [2, 4, 6]);
+    assert_eq!(array.iter().len(), 3);
+}
+
+#[test]
+fn test_array_remove_front() {
+    static COUNT: AtomicUsize = AtomicUsize::new(0);
+    struct Lolka(usize);
+
+    impl Drop for Lolka {
+        fn drop(&mut self) {
+            COUNT.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let mut array = Array::<Lolka, 8>::new();
+    assert!(array.remove_front().is_none());
+
+    for idx in 0..3 {
+        assert!(array.push(Lolka(idx)).is_none());
+    }
+
+    let first = array.remove_front().expect("Not empty");
+    assert_eq!(first.0, 0);
+    assert_eq!(COUNT.load(Ordering::Relaxed), 0);
+    drop(first);
+    assert_eq!(COUNT.load(Ordering::Relaxed), 1);
+    assert_eq!(array.iter().map(|elem| elem.0).collect::<Vec<_>>(), [1, 2]);
+
+    assert_eq!(array.remove_front().map(|elem| elem.0), Some(1));
+    assert_eq!(array.remove_front().map(|elem| elem.0), Some(2));
+    assert!(array.remove_front().is_none());
+    assert_eq!(COUNT.load(Ordering::Relaxed), 3);
+}
+
+#[test]
+fn test_array_from_const_array() {
+    static FOO: Array<u8, 4> = Array::from_const_array(*b"lolk");
+    const BAR: Array<u32, 2> = Array::from_const_array([1, 2]);
+
+    assert!(FOO.is_full());
+    assert_eq!(FOO, *b"lolk");
+    assert_eq!(BAR, [1, 2]);
+}
+
+#[test]
+fn test_array_insert_from_slice() {
+    let mut array = Array::<u8, 12>::new();
+    array.extend_from_slice(b"payload");
+
+    assert_eq!(array.insert_from_slice(0, b"hdr:"), Ok(()));
+    assert_eq!(array, *b"hdr:payload");
+    assert_eq!(array.insert_from_slice(4, b" "), Ok(()));
+    assert_eq!(array, *b"hdr: payload");
+    assert_eq!(array.insert_from_slice(12, b""), Ok(()));
+
+    array.truncate(10);
+    assert_eq!(array.insert_from_slice(3, b"xyz"), Err(statiki::CapacityError(())));
+    assert_eq!(array, *b"hdr: paylo");
+    assert_eq!(array.insert_from_slice(10, b"ad"), Ok(()));
+    assert_eq!(array, *b"hdr: payload");
+}
+
+#[test]
+#[should_panic(expected = "insertion index (is 3) should be <= len (is 2)")]
+fn test_array_insert_from_slice_out_of_bounds() {
+    let mut array = Array::<u8, 12>::new();
+    array.extend_from_slice(b"ab");
+    let _ = array.insert_from_slice(3, b"c");
+}
+
+#[test]
+fn test_array_split_at_spare_mut() {
+    let mut array = Array::<u8, 8>::new();
+    array.extend_from_slice(b"abc");
+
+    let (init, spare) = array.split_at_spare_mut();
+    assert_eq!(init.len(), 3);
+    assert_eq!(spare.len(), 5);
+    assert_eq!(unsafe { init.as_ptr().add(init.len()) }, spare.as_ptr() as *const u8);
+
+    for (dest, src) in spare.iter_mut().zip(init.iter()) {
+        dest.write(src.to_ascii_uppercase());
+    }
+    unsafe {
+        array.set_len(6);
+    }
+    assert_eq!(array, *b"abcABC");
+
+    let (init, spare) = array.split_at_spare_mut();
+    assert_eq!(init.len(), 6);
+    assert_eq!(spare.len(), 2);
+}
+
+#[test]
+fn test_array_zero_capacity() {
+    static COUNT: AtomicUsize = AtomicUsize::new(0);
+    struct Lolka;
+
+    impl Drop for Lolka {
+        fn drop(&mut self) {
+            COUNT.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let mut array = Array::<u8, 0>::new();
+    assert_eq!(array.capacity(), 0);
+    assert_eq!(array.remaining_capacity(), 0);
+    assert!(array.is_empty());
+    assert!(array.as_slice().is_empty());
+    assert!(array.as_mut_slice().is_empty());
+    assert!(!array.as_ptr().is_null());
+    assert_eq!(array.push(1), Some(1));
+    assert_eq!(array.try_push(2), Err(statiki::array::CapacityError(2)));
+    assert_eq!(array.pop(), None);
+    assert_eq!(array.iter().count(), 0);
+    assert_eq!(array, *b"");
+    array.clear();
+    assert!(array.is_empty());
+
+    let mut array = Array::<Lolka, 0>::new();
+    let rejected = array.push(Lolka);
+    assert!(rejected.is_some());
+    drop(rejected);
+    assert_eq!(COUNT.load(Ordering::Relaxed), 1);
+    drop(array);
+    assert_eq!(COUNT.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn test_array_chunks() {
+    let mut array = Array::<u32, 16>::new();
+    array.extend_from_slice(&[1, 2, 3, 4, 5, 6, 7]);
+
+    let mut chunks = array.chunks(3);
+    assert_eq!(chunks.next(), Some([1, 2, 3].as_ref()));
+    assert_eq!(chunks.next(), Some([4, 5, 6].as_ref()));
+    assert_eq!(chunks.next(), Some([7].as_ref()));
+    assert_eq!(chunks.next(), None);
+
+    for chunk in array.chunks_mut(3) {
+        let len = chunk.len() as u32;
+        for elem in chunk.iter_mut() {
+            *elem *= len;
+        }
+    }
+    assert_eq!(array, [3, 6, 9, 12, 15, 18, 7]);
+    assert_eq!(Array::<u32, 16>::new().chunks(3).count(), 0);
+}
+
+#[test]
+fn test_array_windows() {
+    let mut array = Array::<i32, 8>::new();
+    array.extend_from_slice(&[4, -2, 7, 1, 0, -5, 3, 9]);
+
+    let mut expected = Vec::new();
+    for idx in 0..=array.len() - 3 {
+        expected.push(array[idx] + array[idx + 1] + array[idx + 2]);
+    }
+    let sums = array.windows(3).map(|window| window.iter().sum::<i32>()).collect::<Vec<_>>();
+    assert_eq!(sums, expected);
+    assert_eq!(sums, [9, 6, 8, -4, -2, 7]);
+
+    assert_eq!(array.windows(8).count(), 1);
+    assert_eq!(array.windows(9).count(), 0);
+}
+
+#[test]
+fn test_array_bytes() {
+    let mut array = Array::<u16, 4>::new();
+    array.extend_from_slice(&[1, 0x0102, u16::MAX]);
+
+    let bytes = unsafe {
+        array.as_bytes()
+    };
+    assert_eq!(bytes.len(), 6);
+    assert_eq!(bytes[2..4], 0x0102u16.to_ne_bytes());
+
+    let restored = unsafe {
+        Array::<u16, 4>::from_bytes(bytes)
+    }.expect("To restore");
+    assert_eq!(restored, array);
+
+    //Unaligned input
+    let mut raw = [0u8; 9];
+    raw[1..].copy_from_slice(&[7u16, 8, 9, 10].iter().flat_map(|elem| elem.to_ne_bytes()).collect::<Vec<_>>());
+    let restored = unsafe {
+        Array::<u16, 4>::from_bytes(&raw[1..])
+    }.expect("To restore");
+    assert_eq!(restored, [7, 8, 9, 10]);
+
+    unsafe {
+        assert!(Array::<u16, 4>::from_bytes(&raw[..3]).is_none());
+        assert!(Array::<u16, 4>::from_bytes(&[0u8; 10]).is_none());
+        assert!(Array::<u16, 4>::from_bytes(&[]).expect("To restore").is_empty());
+        assert!(Array::<u16, 4>::new().as_bytes().is_empty());
+    }
+}
+
+#[test]
+fn test_array_reverse() {
+    let mut array = Array::<u8, 8>::new();
+    array.extend_from_slice(b"abcdefgh");
+    array.truncate(5);
+    array.reverse();
+
+    assert_eq!(array.len(), 5);
+    assert_eq!(array, *b"edcba");
+    unsafe {
+        array.set_len(8);
+    }
+    assert_eq!(array, *b"edcbafgh");
+
+    let mut array = Array::<u8, 8>::new();
+    array.reverse();
+    assert!(array.is_empty());
+}
+
+#[test]
+fn test_array_from_fn() {
+    let array = Array::<usize, 8>::from_fn(|idx| idx * idx);
+    assert!(array.is_full());
+    assert_eq!(array, [0, 1, 4, 9, 16, 25, 36, 49]);
+
+    assert!(Array::<usize, 0>::from_fn(|idx| idx).is_empty());
+}
+
+#[test]
+fn test_array_from_fn_panic() {
+    static COUNT: AtomicUsize = AtomicUsize::new(0);
+    struct Lolka;
+
+    impl Drop for Lolka {
+        fn drop(&mut self) {
+            COUNT.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let result = std::panic::catch_unwind(|| {
+        Array::<Lolka, 8>::from_fn(|idx| match idx {
+            3 => panic!("lolka"),
+            _ => Lolka,
+        })
+    });
+    assert!(result.is_err());
+    assert_eq!(COUNT.load(Ordering::Relaxed), 3);
+}
+
+#[test]
+fn test_array_try_swap_remove() {
+    let mut array = Array::<u8, 8>::new();
+    array.extend_from_slice(b"abcd");
+
+    assert_eq!(array.try_swap_remove(1), Some(b'b'));
+    assert_eq!(array, *b"adc");
+    assert_eq!(array.try_swap_remove(2), Some(b'c'));
+    assert_eq!(array, *b"ad");
+
+    assert_eq!(array.try_swap_remove(2), None);
+    assert_eq!(array.try_swap_remove(usize::MAX), None);
+    assert_eq!(array, *b"ad");
+
+    array.clear();
+    assert_eq!(array.try_swap_remove(0), None);
+}
@@ -0,0 +1,12 @@
+#![cfg(feature = "defmt")]
+
+fn assert_format<T: defmt::Format>() {
+}
+
+#[test]
+fn test_defmt_format() {
+    assert_format::<statiki::Array<u32, 4>>();
+    assert_format::<statiki::Array<statiki::Array<u8, 2>, 4>>();
+    assert_format::<statiki::RingBuffer<u32, 4>>();
+    assert_format::<statiki::RingBuffer<String, 4>>();
+}
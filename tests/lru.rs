@@ -0,0 +1,64 @@
+use statiki::LruCache;
+use statiki::lru::Displaced;
+
+#[test]
+fn test_lru_cache_eviction() {
+    let mut cache = LruCache::<u32, &str, 3>::new();
+    assert_eq!(cache.capacity(), 3);
+    assert!(cache.is_empty());
+
+    assert!(cache.put(1, "one").is_none());
+    assert!(cache.put(2, "two").is_none());
+    assert!(cache.put(3, "three").is_none());
+    assert_eq!(cache.len(), 3);
+
+    assert_eq!(cache.put(4, "four"), Some(Displaced::Evicted(1, "one")));
+    assert_eq!(cache.put(5, "five"), Some(Displaced::Evicted(2, "two")));
+    assert_eq!(cache.len(), 3);
+    assert!(cache.get(&1).is_none());
+    assert!(cache.get(&2).is_none());
+
+    assert_eq!(cache.put(5, "FIVE"), Some(Displaced::Replaced("five")));
+    assert_eq!(cache.len(), 3);
+    assert_eq!(cache.put(6, "six"), Some(Displaced::Evicted(3, "three")));
+    assert_eq!(cache.put(7, "seven"), Some(Displaced::Evicted(4, "four")));
+    assert_eq!(cache.get(&5), Some(&"FIVE"));
+
+    let mut empty = LruCache::<u32, u32, 0>::new();
+    assert_eq!(empty.put(1, 1), Some(Displaced::Evicted(1, 1)));
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn test_lru_cache_get_updates_recency() {
+    let mut cache = LruCache::<u32, u32, 3>::new();
+
+    assert!(cache.put(1, 10).is_none());
+    assert!(cache.put(2, 20).is_none());
+    assert!(cache.put(3, 30).is_none());
+
+    assert_eq!(cache.get(&1), Some(&10));
+    assert_eq!(cache.put(4, 40), Some(Displaced::Evicted(2, 20)));
+
+    *cache.get_mut(&3).expect("To get value") += 1;
+    assert_eq!(cache.put(5, 50), Some(Displaced::Evicted(1, 10)));
+    assert_eq!(cache.put(6, 60), Some(Displaced::Evicted(4, 40)));
+    assert_eq!(cache.get(&3), Some(&31));
+    assert_eq!(format!("{:?}", cache), "{5: 50, 6: 60, 3: 31}");
+}
+
+#[test]
+fn test_lru_cache_put_displaced() {
+    let mut cache = LruCache::<u32, u32, 2>::new();
+
+    assert!(cache.put(1, 10).is_none());
+    assert!(cache.put(2, 20).is_none());
+
+    assert_eq!(cache.put(1, 11), Some(Displaced::Replaced(10)));
+    assert_eq!(cache.len(), 2);
+    assert_eq!(cache.get(&2), Some(&20));
+
+    assert_eq!(cache.put(3, 30), Some(Displaced::Evicted(1, 11)));
+    assert_eq!(cache.len(), 2);
+    assert!(cache.get(&1).is_none());
+}
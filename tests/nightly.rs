@@ -0,0 +1,26 @@
+#![cfg(feature = "nightly")]
+#![allow(incomplete_features)]
+#![feature(generic_const_exprs)]
+
+use statiki::Array;
+
+#[test]
+fn test_array_concat() {
+    let mut left = Array::<String, 4>::new();
+    assert!(left.push(String::from("a")).is_none());
+    assert!(left.push(String::from("b")).is_none());
+    let mut right = Array::<String, 3>::new();
+    assert!(right.push(String::from("c")).is_none());
+
+    let array = left.concat(right);
+    assert_eq!(array.capacity(), 7);
+    assert_eq!(array.len(), 3);
+    assert_eq!(array, ["a", "b", "c"].iter().map(|elem| String::from(*elem)).collect::<Vec<_>>().as_slice());
+
+    let array = array.concat(Array::<String, 1>::new());
+    assert_eq!(array.capacity(), 8);
+    assert_eq!(array.len(), 3);
+
+    let array = Array::<u8, 0>::new().concat(Array::<u8, 0>::new());
+    assert_eq!(array.capacity(), 0);
+}
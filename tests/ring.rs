@@ -1,3 +1,5 @@
+use core::sync::atomic::{AtomicUsize, Ordering};
+
 type RingBuffer<T> = statiki::RingBuffer<T, 512>;
 
 #[test]
@@ -76,3 +78,981 @@ fn test_ring_buffer() {
     assert_eq!(queue.size(), 512);
     assert_eq!(queue.try_push(999), Some(999));
 }
+
+#[test]
+fn test_ring_buffer_median() {
+    let mut queue = statiki::RingBuffer::<u32, 8>::new();
+    assert!(queue.median().is_none());
+    assert!(queue.percentile(90).is_none());
+
+    for value in [9, 1, 7, 3, 5] {
+        queue.push(value);
+    }
+    assert_eq!(queue.median(), Some(5));
+    assert_eq!(queue.percentile(0), Some(1));
+    assert_eq!(queue.percentile(100), Some(9));
+    assert_eq!(queue.size(), 5);
+
+    //Window moves past first elements
+    for value in [20, 30, 40, 50, 60, 70] {
+        queue.push(value);
+    }
+    //Window: 3, 5, 20, 30, 40, 50, 60, 70
+    assert_eq!(queue.size(), 8);
+    assert_eq!(queue.median(), Some(30));
+    assert_eq!(queue.percentile(0), Some(3));
+    assert_eq!(queue.percentile(100), Some(70));
+    assert_eq!(queue.pop(), Some(3));
+}
+
+#[test]
+fn test_ring_buffer_fmt_write() {
+    use core::fmt::Write;
+
+    let mut queue = statiki::RingBuffer::<u8, 8>::new();
+    write!(queue, "log").expect("To write");
+    assert_eq!(queue.size(), 3);
+
+    write!(queue, "|line {}|", 12345).expect("To write");
+    assert_eq!(queue.size(), 8);
+
+    let mut result = [0u8; 8];
+    for byte in result.iter_mut() {
+        *byte = queue.pop().expect("Value");
+    }
+    assert_eq!(&result, b"e 12345|");
+    assert!(queue.is_empty());
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(miri, ignore)] //Buffer is leaked intentionally
+#[test]
+fn test_ring_buffer_leak_split() {
+    let queue = Box::new(RingBuffer::<usize>::new());
+    let (producer, consumer) = queue.leak_split();
+
+    let consumer = std::thread::spawn(move || {
+        let mut expected = 0;
+        while expected <= 1000 {
+            if let Some(value) = consumer.pop() {
+                assert_eq!(value, expected);
+                expected += 1;
+            }
+        }
+    });
+
+    let producer = std::thread::spawn(move || {
+        for idx in 0..=1000 {
+            while producer.try_push(idx).is_some() {
+            }
+        }
+    });
+
+    producer.join().expect("Success");
+    consumer.join().expect("Success");
+}
+
+#[test]
+fn test_ring_buffer_drain() {
+    static COUNT: AtomicUsize = AtomicUsize::new(0);
+    struct Lolka(usize);
+
+    impl Drop for Lolka {
+        fn drop(&mut self) {
+            COUNT.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let mut queue = statiki::RingBuffer::<Lolka, 8>::new();
+    for idx in 0..8 {
+        queue.push(Lolka(idx));
+    }
+
+    let drain = queue.drain();
+    assert_eq!(drain.len(), 8);
+    assert_eq!(drain.map(|elem| elem.0).collect::<Vec<_>>(), [0, 1, 2, 3, 4, 5, 6, 7]);
+    assert_eq!(COUNT.load(Ordering::Relaxed), 8);
+    assert!(queue.is_empty());
+
+    //Wrap around
+    for idx in 0..13 {
+        queue.push(Lolka(idx));
+    }
+    assert_eq!(COUNT.load(Ordering::Relaxed), 13);
+
+    let mut drain = queue.drain();
+    assert_eq!(drain.len(), 8);
+    assert_eq!(drain.next().map(|elem| elem.0), Some(5));
+    assert_eq!(drain.next().map(|elem| elem.0), Some(6));
+    assert_eq!(drain.len(), 6);
+    drop(drain);
+    assert_eq!(COUNT.load(Ordering::Relaxed), 21);
+    assert!(queue.is_empty());
+    assert!(queue.pop().is_none());
+}
+
+#[test]
+fn test_ring_buffer_peek() {
+    let mut queue = statiki::RingBuffer::<usize, 4>::new();
+    assert!(queue.peek().is_none());
+
+    for idx in 0..6 {
+        queue.push(idx);
+    }
+
+    for expected in 2..6 {
+        assert_eq!(queue.peek(), Some(&expected));
+        assert_eq!(queue.peek(), Some(&expected));
+        assert_eq!(queue.pop(), Some(expected));
+    }
+
+    assert!(queue.peek().is_none());
+}
+
+#[test]
+fn test_ring_buffer_peek_at() {
+    let mut queue = statiki::RingBuffer::<usize, 4>::new();
+    assert!(queue.peek_at(0).is_none());
+
+    queue.push(0);
+    queue.push(1);
+    assert_eq!(queue.peek_at(0), Some(&0));
+    assert_eq!(queue.peek_at(1), Some(&1));
+    assert!(queue.peek_at(2).is_none());
+
+    //Wrap around
+    for idx in 2..7 {
+        queue.push(idx);
+    }
+    for offset in 0..4 {
+        assert_eq!(queue.peek_at(offset), Some(&(offset + 3)));
+    }
+    assert!(queue.peek_at(4).is_none());
+    assert!(queue.peek_at(usize::MAX).is_none());
+
+    assert_eq!(queue.pop(), Some(3));
+    assert_eq!(queue.peek_at(0), Some(&4));
+    assert_eq!(queue.peek_at(2), Some(&6));
+    assert!(queue.peek_at(3).is_none());
+}
+
+#[test]
+fn test_ring_buffer_iter() {
+    let mut queue = statiki::RingBuffer::<usize, 4>::new();
+    assert!(queue.iter().next().is_none());
+
+    for idx in 0..3 {
+        queue.push(idx);
+    }
+    assert_eq!(queue.iter().len(), 3);
+    assert_eq!(queue.iter().copied().collect::<Vec<_>>(), [0, 1, 2]);
+
+    //Wrap around
+    for idx in 3..7 {
+        queue.push(idx);
+    }
+    let mut iter = queue.iter();
+    assert_eq!(iter.next(), Some(&3));
+    assert_eq!(iter.next_back(), Some(&6));
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.copied().collect::<Vec<_>>(), [4, 5]);
+    assert_eq!(queue.iter().rev().copied().collect::<Vec<_>>(), [6, 5, 4, 3]);
+
+    let expected = queue.iter().copied().collect::<Vec<_>>();
+    assert_eq!(queue.size(), 4);
+    assert_eq!(queue.drain().collect::<Vec<_>>(), expected);
+}
+
+#[test]
+fn test_ring_buffer_clear_count() {
+    let mut queue = statiki::RingBuffer::<usize, 4>::new();
+    assert_eq!(queue.clear(), 0);
+
+    for idx in 0..6 {
+        queue.push(idx);
+    }
+    queue.pop();
+    let size = queue.size();
+    assert_eq!(queue.clear(), size);
+    assert!(queue.is_empty());
+
+    let mut queue = statiki::RingBuffer::<String, 4>::new();
+    for idx in 0..3 {
+        queue.push(idx.to_string());
+    }
+    assert_eq!(queue.clear(), 3);
+    assert_eq!(queue.clear(), 0);
+}
+
+#[test]
+fn test_ring_buffer_pop_into() {
+    let mut queue = statiki::RingBuffer::<String, 8>::new();
+    assert!(queue.pop_into::<4>().is_empty());
+
+    //Wrap around
+    for idx in 0..11 {
+        queue.push(idx.to_string());
+    }
+
+    let array = queue.pop_into::<5>();
+    assert_eq!(array, ["3", "4", "5", "6", "7"].map(String::from).as_ref());
+    assert_eq!(queue.size(), 3);
+
+    let array = queue.pop_into::<5>();
+    assert_eq!(array, ["8", "9", "10"].map(String::from).as_ref());
+    assert!(queue.is_empty());
+}
+
+#[test]
+fn test_ring_buffer_push_evicted() {
+    let mut queue = statiki::RingBuffer::<usize, 4>::new();
+
+    for idx in 0..4 {
+        assert!(queue.push(idx).is_none());
+    }
+
+    for idx in 4..10 {
+        assert_eq!(queue.push(idx), Some(idx - 4));
+    }
+
+    assert_eq!(queue.size(), 4);
+    for expected in 6..10 {
+        assert_eq!(queue.pop(), Some(expected));
+    }
+}
+
+#[test]
+fn test_ring_buffer_force_push() {
+    for mut queue in [statiki::RingBuffer::<usize, 3>::new_overwrite(), statiki::RingBuffer::<usize, 3>::new_backpressure()] {
+        assert!(queue.force_push(0).is_none());
+        assert!(queue.force_push(1).is_none());
+        assert!(queue.force_push(2).is_none());
+        assert!(queue.is_full());
+
+        assert_eq!(queue.force_push(3), Some(0));
+        assert_eq!(queue.force_push(4), Some(1));
+        assert_eq!(queue.size(), 3);
+        assert_eq!(queue.drain().collect::<Vec<_>>(), [2, 3, 4]);
+    }
+}
+
+#[test]
+fn test_ring_buffer_push_policy() {
+    let mut queue = statiki::RingBuffer::<usize, 2>::new();
+    assert!(queue.is_overwrite());
+
+    assert!(queue.push(0).is_none());
+    assert!(queue.push(1).is_none());
+    assert_eq!(queue.push(2), Some(0));
+    assert_eq!(queue.clone().drain().collect::<Vec<_>>(), [1, 2]);
+    assert_eq!(queue.clone().push(3), Some(1));
+
+    let mut queue = statiki::RingBuffer::<usize, 2>::new_overwrite();
+    assert!(queue.is_overwrite());
+    for idx in 0..5 {
+        queue.push(idx);
+    }
+    assert_eq!(queue.drain().collect::<Vec<_>>(), [3, 4]);
+
+    let mut queue = statiki::RingBuffer::<usize, 2>::new_backpressure();
+    assert!(!queue.is_overwrite());
+
+    assert!(queue.push(0).is_none());
+    assert!(queue.push(1).is_none());
+    assert_eq!(queue.push(2), Some(2));
+    assert_eq!(queue.clone().push(3), Some(3));
+    assert_eq!(queue.force_push(4), Some(0));
+    assert_eq!(queue.drain().collect::<Vec<_>>(), [1, 4]);
+}
+
+#[test]
+fn test_ring_buffer_backpressure_traits() {
+    use core::fmt::Write;
+
+    let mut queue = statiki::RingBuffer::<usize, 4>::new_backpressure();
+    queue.extend(0..3);
+    queue.extend(3..10);
+    assert!(!queue.is_overwrite());
+    assert_eq!(queue.drain().collect::<Vec<_>>(), [0, 1, 2, 3]);
+
+    let mut queue = statiki::RingBuffer::<u8, 8>::new_backpressure();
+    assert!(queue.write_str("lolka").is_ok());
+    assert!(queue.write_str("kek!").is_err());
+    assert!(queue.write_str("kek").is_ok());
+    assert!(queue.write_str("").is_ok());
+    assert!(queue.write_str("!").is_err());
+    assert_eq!(queue.drain().collect::<Vec<_>>(), b"lolkakek");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_ring_buffer_backpressure_io_write() {
+    use std::io::Write;
+
+    let mut queue = statiki::RingBuffer::<u8, 8>::new_backpressure();
+    assert_eq!(queue.write(b"abc").expect("To write"), 3);
+    assert_eq!(queue.write(b"0123456789").expect("To write"), 5);
+    assert_eq!(queue.write(b"!").expect("To write"), 0);
+    assert!(queue.write_all(b"!").is_err());
+    queue.flush().expect("To flush");
+    assert_eq!(queue.drain().collect::<Vec<_>>(), b"abc01234");
+}
+
+#[test]
+fn test_ring_buffer_try_push_full() {
+    let mut queue = statiki::RingBuffer::<usize, 4>::new();
+
+    for idx in 0..4 {
+        assert!(queue.try_push(idx).is_none());
+    }
+    assert!(queue.is_full());
+
+    for idx in 4..8 {
+        assert_eq!(queue.try_push(idx), Some(idx));
+    }
+
+    assert_eq!(queue.size(), 4);
+    for expected in 0..4 {
+        assert_eq!(queue.pop(), Some(expected));
+    }
+    assert!(queue.pop().is_none());
+}
+
+#[test]
+fn test_ring_buffer_push_within_capacity() {
+    let mut queue = statiki::RingBuffer::<usize, 4>::new();
+
+    for idx in 0..4 {
+        assert_eq!(queue.push_within_capacity(idx), Ok(()));
+    }
+    assert_eq!(queue.push_within_capacity(4), Err(4));
+
+    assert_eq!(queue.pop(), Some(0));
+    assert_eq!(queue.push_within_capacity(5), Ok(()));
+    assert_eq!(queue.drain().collect::<Vec<_>>(), [1, 2, 3, 5]);
+}
+
+#[test]
+fn test_ring_buffer_try_push_reporting() {
+    let mut queue = statiki::RingBuffer::<String, 4>::new();
+
+    for idx in 0..4 {
+        assert_eq!(queue.try_push_reporting(idx.to_string()), Ok(idx + 1));
+    }
+    assert_eq!(queue.try_push_reporting("4".to_owned()), Err("4".to_owned()));
+    assert_eq!(queue.size(), 4);
+
+    queue.pop();
+    queue.pop();
+    assert_eq!(queue.try_push_reporting("5".to_owned()), Ok(3));
+}
+
+#[test]
+fn test_ring_buffer_len() {
+    let mut queue = statiki::RingBuffer::<usize, 8>::new();
+    assert_eq!(queue.len(), 0);
+    assert_eq!(queue.remaining_capacity(), 8);
+
+    for idx in 0..5 {
+        queue.push(idx);
+    }
+    assert_eq!(queue.len(), 5);
+    assert_eq!(queue.remaining_capacity(), 3);
+
+    queue.pop();
+    queue.pop();
+    assert_eq!(queue.len(), 3);
+    assert_eq!(queue.remaining_capacity(), 5);
+
+    for idx in 0..10 {
+        queue.push(idx);
+    }
+    assert_eq!(queue.len(), 8);
+    assert_eq!(queue.remaining_capacity(), 0);
+}
+
+#[test]
+fn test_ring_buffer_clone() {
+    let mut queue = statiki::RingBuffer::<String, 8>::new();
+    for idx in 0..13 {
+        queue.push(idx.to_string());
+    }
+    queue.pop();
+
+    let mut cloned = queue.clone();
+    assert_eq!(cloned.size(), queue.size());
+
+    while let Some(expected) = queue.pop() {
+        assert_eq!(cloned.pop(), Some(expected));
+    }
+    assert!(cloned.pop().is_none());
+}
+
+#[test]
+fn test_ring_buffer_push_slice() {
+    let mut queue = statiki::RingBuffer::<u8, 8>::new();
+
+    assert_eq!(queue.push_slice(b"abcde"), 5);
+    assert_eq!(queue.size(), 5);
+    for expected in b"abc" {
+        assert_eq!(queue.pop(), Some(*expected));
+    }
+
+    //Write crosses end of storage
+    assert_eq!(queue.push_slice(b"fghij"), 5);
+    assert_eq!(queue.size(), 7);
+    //Only single free slot remains
+    assert_eq!(queue.push_slice(b"klm"), 1);
+    assert_eq!(queue.push_slice(b"klm"), 0);
+    assert!(queue.is_full());
+
+    for expected in b"defghijk" {
+        assert_eq!(queue.pop(), Some(*expected));
+    }
+    assert!(queue.is_empty());
+
+    assert_eq!(queue.push_slice(b"0123456789"), 8);
+    for expected in b"01234567" {
+        assert_eq!(queue.pop(), Some(*expected));
+    }
+    assert!(queue.is_empty());
+}
+
+#[test]
+fn test_ring_buffer_pop_slice() {
+    let mut queue = statiki::RingBuffer::<u8, 8>::new();
+    let mut out = [0u8; 16];
+
+    assert_eq!(queue.pop_slice(&mut out), 0);
+
+    assert_eq!(queue.push_slice(b"abcdef"), 6);
+    assert_eq!(queue.pop_slice(&mut out[..4]), 4);
+    assert_eq!(&out[..4], b"abcd");
+
+    //Read crosses end of storage
+    assert_eq!(queue.push_slice(b"ghijkl"), 6);
+    assert_eq!(queue.pop_slice(&mut out[..5]), 5);
+    assert_eq!(&out[..5], b"efghi");
+
+    //Output is larger than available data
+    assert_eq!(queue.pop_slice(&mut out), 3);
+    assert_eq!(&out[..3], b"jkl");
+    assert!(queue.is_empty());
+    assert_eq!(queue.pop_slice(&mut out), 0);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_ring_buffer_io_write() {
+    use std::io::Write;
+
+    let mut queue = statiki::RingBuffer::<u8, 8>::new();
+    assert_eq!(queue.write(b"abc").expect("To write"), 3);
+    assert_eq!(queue.write(b"0123456789").expect("To write"), 10);
+    queue.flush().expect("To flush");
+    assert!(queue.is_full());
+
+    let mut out = [0u8; 8];
+    assert_eq!(queue.pop_slice(&mut out), 8);
+    assert_eq!(&out, b"23456789");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_ring_buffer_io_read() {
+    use std::io::{Read, Write};
+
+    let mut queue = statiki::RingBuffer::<u8, 16>::new();
+    let mut out = [0u8; 5];
+
+    queue.write_all(b"statiki").expect("To write");
+    assert_eq!(queue.read(&mut out).expect("To read"), 5);
+    assert_eq!(&out, b"stati");
+
+    queue.write_all(b" ring buffer").expect("To write");
+    assert_eq!(queue.read(&mut out).expect("To read"), 5);
+    assert_eq!(&out, b"ki ri");
+
+    let mut rest = Vec::new();
+    queue.read_to_end(&mut rest).expect("To read");
+    assert_eq!(rest, b"ng buffer");
+    assert_eq!(queue.read(&mut out).expect("To read"), 0);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_ring_buffer_io_pump() {
+    use std::io::Cursor;
+
+    let mut queue = statiki::RingBuffer::<u8, 8>::new_backpressure();
+    queue.push_slice(b"01234");
+    queue.clear();
+
+    //Free region wraps around end of storage
+    let mut input = Cursor::new(b"abcdefghij".to_vec());
+    assert_eq!(queue.write_from_reader(&mut input).expect("To read"), 8);
+    assert_eq!(input.position(), 8);
+    assert!(queue.is_full());
+    assert_eq!(queue.write_from_reader(&mut input).expect("To read"), 0);
+
+    let mut output = Cursor::new(Vec::new());
+    assert_eq!(queue.read_to_writer(&mut output).expect("To write"), 8);
+    assert_eq!(output.get_ref(), b"abcdefgh");
+    assert!(queue.is_empty());
+
+    assert_eq!(queue.write_from_reader(&mut input).expect("To read"), 2);
+    assert_eq!(queue.read_to_writer(&mut output).expect("To write"), 2);
+    assert_eq!(output.get_ref(), b"abcdefghij");
+
+    //Output accepts only part of stored bytes
+    let mut short = [0u8; 3];
+    assert_eq!(queue.write_from_reader(&mut Cursor::new(b"klmno")).expect("To read"), 5);
+    assert_eq!(queue.read_to_writer(&mut Cursor::new(short.as_mut())).expect("To write"), 3);
+    assert_eq!(&short, b"klm");
+    assert_eq!(queue.drain().collect::<Vec<_>>(), b"no");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_ring_buffer_io_pump_overwrite() {
+    use std::io::Cursor;
+
+    let mut queue = statiki::RingBuffer::<u8, 6>::new_overwrite();
+    queue.push_slice(b"0123");
+    assert_eq!(queue.pop(), Some(b'0'));
+
+    let mut input = Cursor::new(b"abcdefghij".to_vec());
+    assert_eq!(queue.write_from_reader(&mut input).expect("To read"), 6);
+    assert_eq!(queue.iter().copied().collect::<Vec<_>>(), b"abcdef");
+
+    queue.pop();
+    assert_eq!(queue.write_from_reader(&mut input).expect("To read"), 4);
+    assert_eq!(queue.iter().copied().collect::<Vec<_>>(), b"efghij");
+
+    let mut output = Vec::new();
+    assert_eq!(queue.read_to_writer(&mut output).expect("To write"), 6);
+    assert_eq!(output, b"efghij");
+    assert!(queue.is_empty());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_ring_buffer_io_pump_invalid_count() {
+    struct LyingReader;
+    impl std::io::Read for LyingReader {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            buf.fill(b'!');
+            Ok(buf.len() + 50)
+        }
+    }
+
+    struct LyingWriter;
+    impl std::io::Write for LyingWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            Ok(buf.len() + 7)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    for mut queue in [statiki::RingBuffer::<u8, 6>::new_backpressure(), statiki::RingBuffer::<u8, 6>::new_overwrite()] {
+        queue.push_slice(&[1, 2]);
+
+        let error = queue.write_from_reader(&mut LyingReader).expect_err("To reject count");
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+        assert_eq!(queue.overwrite_count(), 0);
+        assert_eq!(queue.iter().copied().collect::<Vec<_>>(), [1, 2]);
+
+        let error = queue.read_to_writer(&mut LyingWriter).expect_err("To reject count");
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+        assert_eq!(queue.drain().collect::<Vec<_>>(), [1, 2]);
+    }
+}
+
+#[test]
+fn test_ring_buffer_from_iter() {
+    let mut queue: statiki::RingBuffer<usize, 8> = (0..5).collect();
+    assert_eq!(queue.size(), 5);
+    assert_eq!(queue.drain().collect::<Vec<_>>(), [0, 1, 2, 3, 4]);
+
+    let mut queue: statiki::RingBuffer<usize, 8> = (0..20).collect();
+    assert_eq!(queue.size(), 8);
+    assert_eq!(queue.drain().collect::<Vec<_>>(), [12, 13, 14, 15, 16, 17, 18, 19]);
+
+    queue.extend(0..3);
+    queue.extend(3..10);
+    assert_eq!(queue.size(), 8);
+    assert_eq!(queue.drain().collect::<Vec<_>>(), [2, 3, 4, 5, 6, 7, 8, 9]);
+}
+
+#[test]
+fn test_ring_buffer_direct_wrap() {
+    const COUNT: usize = if cfg!(miri) { 100 } else { 10_000 };
+
+    let mut queue = statiki::RingBuffer::<usize, 8>::new();
+    let mut expected = 0;
+    for idx in 0..COUNT {
+        if let Some(value) = queue.try_push(idx) {
+            assert_eq!(value, idx);
+            for _ in 0..3 {
+                assert_eq!(queue.pop(), Some(expected));
+                expected += 1;
+            }
+            assert!(queue.try_push(idx).is_none());
+        }
+    }
+    while let Some(value) = queue.pop() {
+        assert_eq!(value, expected);
+        expected += 1;
+    }
+    assert_eq!(expected, COUNT);
+
+    let mut queue = statiki::RingBuffer::<u8, 8>::new();
+    let mut out = [0u8; 8];
+    for _ in 0..3 {
+        assert_eq!(queue.push_slice(b"012345"), 6);
+        assert_eq!(queue.push_slice(b"6789"), 2);
+        assert_eq!(queue.pop_slice(&mut out[..5]), 5);
+        assert_eq!(&out[..5], b"01234");
+        assert_eq!(queue.pop_slice(&mut out), 3);
+        assert_eq!(&out[..3], b"567");
+    }
+}
+
+#[test]
+fn test_ring_buffer_split_slice() {
+    const COUNT: usize = if cfg!(miri) { 1_000 } else { 1_000_000 };
+
+    let mut queue = statiki::RingBuffer::<usize, 64>::new();
+    let (producer, consumer) = queue.split();
+
+    std::thread::scope(move |scope| {
+        let consumer = scope.spawn(move || {
+            let mut out = [0usize; 24];
+            let mut expected = 0;
+            while expected < COUNT {
+                let len = consumer.pop_slice(&mut out);
+                if len == 0 {
+                    std::thread::yield_now();
+                }
+                for value in &out[..len] {
+                    assert_eq!(*value, expected);
+                    expected += 1;
+                }
+            }
+            assert_eq!(consumer.pop_slice(&mut out), 0);
+        });
+
+        let mut data = [0usize; 20];
+        let mut sent = 0;
+        while sent < COUNT {
+            let len = core::cmp::min(data.len(), COUNT - sent);
+            for (idx, value) in data[..len].iter_mut().enumerate() {
+                *value = sent + idx;
+            }
+
+            let mut written = 0;
+            while written < len {
+                match producer.push_slice(&data[written..len]) {
+                    0 => std::thread::yield_now(),
+                    count => written += count,
+                }
+            }
+            sent += len;
+        }
+
+        consumer.join().expect("Success");
+    });
+}
+
+#[test]
+fn test_ring_buffer_consumer_peek() {
+    let mut queue = statiki::RingBuffer::<usize, 8>::new();
+    let (producer, mut consumer) = queue.split();
+
+    assert!(consumer.peek().is_none());
+    for idx in 0..6 {
+        assert!(producer.try_push(idx).is_none());
+    }
+
+    let mut even = Vec::new();
+    while let Some(&value) = consumer.peek() {
+        if value % 2 == 0 {
+            even.push(value);
+        }
+        assert_eq!(consumer.pop(), Some(value));
+    }
+    assert_eq!(even, [0, 2, 4]);
+    assert!(consumer.pop().is_none());
+}
+
+#[test]
+fn test_ring_buffer_consumer_iter() {
+    let mut queue = statiki::RingBuffer::<usize, 8>::new();
+    let (producer, mut consumer) = queue.split();
+
+    for idx in 0..5 {
+        assert!(producer.try_push(idx).is_none());
+    }
+
+    let mut result = Vec::new();
+    for item in &consumer {
+        result.push(item);
+    }
+    assert_eq!(result, [0, 1, 2, 3, 4]);
+    assert!(consumer.next().is_none());
+
+    for idx in 5..8 {
+        assert!(producer.try_push(idx).is_none());
+    }
+    assert_eq!(consumer.by_ref().map(|item| item * 2).collect::<Vec<_>>(), [10, 12, 14]);
+}
+
+#[test]
+fn test_ring_buffer_zst() {
+    let mut queue = statiki::RingBuffer::<(), 4>::new();
+    assert_eq!(core::mem::size_of_val(&queue), core::mem::size_of::<statiki::RingBuffer::<(), 1024>>());
+
+    for idx in 0..4 {
+        assert_eq!(queue.size(), idx);
+        assert!(queue.try_push(()).is_none());
+    }
+    assert!(queue.is_full());
+    assert_eq!(queue.try_push(()), Some(()));
+    assert_eq!(queue.push(()), Some(()));
+    assert_eq!(queue.size(), 4);
+
+    for idx in (0..4).rev() {
+        assert_eq!(queue.pop(), Some(()));
+        assert_eq!(queue.size(), idx);
+    }
+    assert!(queue.pop().is_none());
+
+    static COUNT: AtomicUsize = AtomicUsize::new(0);
+    struct Lolka;
+
+    impl Drop for Lolka {
+        fn drop(&mut self) {
+            COUNT.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let mut queue = statiki::RingBuffer::<Lolka, 4>::new();
+    for _ in 0..6 {
+        queue.push(Lolka);
+    }
+    //Two elements are evicted by overwrite
+    assert_eq!(COUNT.load(Ordering::Relaxed), 2);
+    assert_eq!(queue.size(), 4);
+
+    drop(queue.pop());
+    assert_eq!(COUNT.load(Ordering::Relaxed), 3);
+    assert_eq!(queue.size(), 3);
+
+    drop(queue);
+    assert_eq!(COUNT.load(Ordering::Relaxed), 6);
+}
+
+#[test]
+fn test_ring_buffer_send() {
+    fn assert_send<T: Send>() {
+    }
+    assert_send::<statiki::RingBuffer<u8, 8>>();
+    assert_send::<statiki::ring::Producer<'static, String, 8>>();
+    assert_send::<statiki::ring::Consumer<'static, String, 8>>();
+
+    let mut queue = statiki::RingBuffer::<u8, 8>::new();
+    queue.push(1);
+
+    let mut queue = std::thread::spawn(move || {
+        queue.push(2);
+        queue
+    }).join().expect("Success");
+    assert_eq!(queue.drain().collect::<Vec<_>>(), [1, 2]);
+}
+
+fn check_ring_buffer_any_capacity<const C: usize>() {
+    let mut queue = statiki::RingBuffer::<usize, C>::new();
+    let mut expected = std::collections::VecDeque::new();
+
+    //Go through several wrap arounds of indexes, which happen at 2 * C
+    for idx in 0..C * 5 + 1 {
+        let evicted = queue.push(idx);
+        expected.push_back(idx);
+        if expected.len() > C {
+            assert_eq!(evicted, expected.pop_front());
+        } else {
+            assert!(evicted.is_none());
+        }
+        assert_eq!(queue.size(), expected.len());
+        assert_eq!(queue.iter().copied().collect::<Vec<_>>(), Vec::from(expected.clone()));
+        assert_eq!(queue.iter().rev().copied().collect::<Vec<_>>(), expected.iter().rev().copied().collect::<Vec<_>>());
+
+        if idx % 3 == 0 {
+            assert_eq!(queue.pop(), expected.pop_front());
+        }
+    }
+
+    queue.clear();
+
+    let data = (0..C + 2).collect::<Vec<_>>();
+    let mut out = vec![0; C + 2];
+    for _ in 0..5 {
+        let written = queue.push_slice(&data[..C / 2 + 1]);
+        assert_eq!(written, core::cmp::min(C, C / 2 + 1));
+        let read = queue.pop_slice(&mut out);
+        assert_eq!(out[..read], data[..written]);
+        assert!(queue.is_empty());
+    }
+
+    assert_eq!(queue.push_slice(&data), C);
+    assert!(queue.is_full());
+    assert_eq!(queue.try_push(0), Some(0));
+    assert_eq!(queue.peek_at(C - 1), Some(&(C - 1)));
+    assert!(queue.peek_at(C).is_none());
+    assert_eq!(queue.drain().collect::<Vec<_>>(), data[..C]);
+}
+
+#[test]
+fn test_ring_buffer_any_capacity() {
+    check_ring_buffer_any_capacity::<1>();
+    check_ring_buffer_any_capacity::<3>();
+    check_ring_buffer_any_capacity::<5>();
+    check_ring_buffer_any_capacity::<8>();
+    check_ring_buffer_any_capacity::<100>();
+
+    let mut queue = statiki::RingBuffer::<usize, 5>::new();
+    let (producer, consumer) = queue.split();
+    std::thread::scope(move |scope| {
+        let consumer = scope.spawn(move || {
+            let mut expected = 0;
+            while expected < 10_000 {
+                match consumer.pop() {
+                    Some(value) => {
+                        assert_eq!(value, expected);
+                        expected += 1;
+                    },
+                    None => std::thread::yield_now(),
+                }
+            }
+        });
+
+        for idx in 0..10_000 {
+            while producer.try_push(idx).is_some() {
+                std::thread::yield_now();
+            }
+        }
+
+        consumer.join().expect("Success");
+    });
+}
+
+#[test]
+fn test_ring_buffer_retain() {
+    static COUNT: AtomicUsize = AtomicUsize::new(0);
+    struct Lolka(usize);
+
+    impl Drop for Lolka {
+        fn drop(&mut self) {
+            COUNT.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let mut queue = statiki::RingBuffer::<Lolka, 8>::new();
+    //Wrap around
+    for idx in 0..11 {
+        if let Some(evicted) = queue.force_push(Lolka(idx)) {
+            core::mem::forget(evicted);
+        }
+    }
+
+    queue.retain(|elem| elem.0 % 2 == 0);
+    assert_eq!(COUNT.load(Ordering::Relaxed), 4);
+    assert_eq!(queue.size(), 4);
+    assert_eq!(queue.iter().map(|elem| elem.0).collect::<Vec<_>>(), [4, 6, 8, 10]);
+
+    queue.force_push(Lolka(11));
+    queue.retain(|_| true);
+    assert_eq!(COUNT.load(Ordering::Relaxed), 4);
+    assert_eq!(queue.iter().map(|elem| elem.0).collect::<Vec<_>>(), [4, 6, 8, 10, 11]);
+
+    queue.retain(|_| false);
+    assert_eq!(COUNT.load(Ordering::Relaxed), 9);
+    assert!(queue.is_empty());
+
+    let mut queue = statiki::RingBuffer::<usize, 5>::new();
+    queue.extend(0..7);
+    queue.retain(|elem| elem % 3 != 0);
+    assert_eq!(queue.drain().collect::<Vec<_>>(), [2, 4, 5]);
+}
+
+#[test]
+fn test_ring_buffer_ref_iterator() {
+    let mut queue = statiki::RingBuffer::<u32, 4>::new();
+    //Wrap around
+    queue.extend(1..=6);
+
+    let mut sum = 0;
+    for elem in &queue {
+        sum += elem;
+    }
+    assert_eq!(sum, 3 + 4 + 5 + 6);
+    assert_eq!((&queue).into_iter().rev().copied().collect::<Vec<_>>(), [6, 5, 4, 3]);
+    assert_eq!(queue.size(), 4);
+}
+
+#[test]
+fn test_ring_buffer_from_array() {
+    let mut queue = statiki::RingBuffer::<String, 3>::from_array([String::from("a"), String::from("b"), String::from("c")]);
+    assert!(queue.is_full());
+    assert_eq!(queue.force_push(String::from("d")).as_deref(), Some("a"));
+    assert_eq!(queue.drain().collect::<Vec<_>>(), ["b", "c", "d"]);
+
+    let mut queue = statiki::RingBuffer::<u8, 4>::from_array([1, 2, 3, 4]);
+    assert_eq!(queue.size(), 4);
+    assert_eq!(queue.pop(), Some(1));
+    assert_eq!(queue.pop(), Some(2));
+    assert_eq!(queue.pop(), Some(3));
+    assert_eq!(queue.pop(), Some(4));
+    assert_eq!(queue.pop(), None);
+}
+
+#[test]
+fn test_ring_buffer_overwrite_count() {
+    let mut queue = statiki::RingBuffer::<usize, 4>::new();
+    assert_eq!(queue.overwrite_count(), 0);
+
+    let mut evicted = 0;
+    for idx in 0..11 {
+        if queue.push(idx).is_some() {
+            evicted += 1;
+        }
+    }
+    assert_eq!(evicted, 7);
+    assert_eq!(queue.overwrite_count(), evicted);
+
+    queue.extend(0..2);
+    assert_eq!(queue.overwrite_count(), 9);
+    assert_eq!(queue.reset_overwrite_count(), 9);
+    assert_eq!(queue.overwrite_count(), 0);
+
+    //Rejected and popped elements are not counted
+    let mut queue = statiki::RingBuffer::<usize, 4>::new_backpressure();
+    for idx in 0..11 {
+        queue.push(idx);
+    }
+    queue.pop();
+    assert_eq!(queue.overwrite_count(), 0);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_ring_buffer_overwrite_count_reader() {
+    let mut queue = statiki::RingBuffer::<u8, 4>::new();
+    queue.push_slice(b"ab");
+
+    assert_eq!(queue.write_from_reader(&mut std::io::Cursor::new(b"cdefg")).expect("To read"), 4);
+    assert_eq!(queue.overwrite_count(), 2);
+}
@@ -0,0 +1,43 @@
+#![cfg(feature = "serde")]
+
+use statiki::{RingBuffer, StaticString};
+
+#[test]
+fn test_ring_buffer_serde() {
+    let mut queue = RingBuffer::<u32, 4>::new();
+    for idx in 0..6 {
+        queue.push(idx);
+    }
+
+    let json = serde_json::to_string(&queue).expect("Serialize");
+    assert_eq!(json, "[2,3,4,5]");
+
+    let mut queue: RingBuffer<u32, 4> = serde_json::from_str(&json).expect("Deserialize");
+    assert_eq!(queue.drain().collect::<Vec<_>>(), [2, 3, 4, 5]);
+
+    let empty = RingBuffer::<u32, 4>::new();
+    assert_eq!(serde_json::to_string(&empty).expect("Serialize"), "[]");
+
+    let error = serde_json::from_str::<RingBuffer<u32, 4>>("[1,2,3,4,5]").unwrap_err();
+    assert!(error.to_string().starts_with("Capacity(4) overflow"));
+}
+
+#[test]
+fn test_static_string_serde() {
+    let mut text = StaticString::<8>::new();
+    text.push_str("ёлка").expect("Success");
+
+    let json = serde_json::to_string(&text).expect("Serialize");
+    assert_eq!(json, "\"ёлка\"");
+
+    let text: StaticString<8> = serde_json::from_str(&json).expect("Deserialize");
+    assert_eq!(text, "ёлка");
+
+    //Escaped string is unescaped before capacity check
+    let text: StaticString<2> = serde_json::from_str("\"\\n\\t\"").expect("Deserialize");
+    assert_eq!(text, "\n\t");
+
+    let error = serde_json::from_str::<StaticString<7>>(&json).unwrap_err();
+    assert!(error.to_string().starts_with("Capacity(7) overflow"));
+    assert!(serde_json::from_str::<StaticString<8>>("[1]").is_err());
+}
@@ -0,0 +1,55 @@
+use statiki::{CapacityError, StaticString};
+
+use core::fmt::Write;
+
+#[test]
+fn test_static_string_push() {
+    let mut text = StaticString::<8>::new();
+    assert!(text.is_empty());
+    assert_eq!(text.capacity(), 8);
+
+    assert_eq!(text.push_str("lolka"), Ok(()));
+    assert_eq!(text.push('!'), Ok(()));
+    assert_eq!(text, "lolka!");
+    assert_eq!(text.len(), 6);
+
+    //Overflow leaves string untouched
+    assert_eq!(text.push_str("abc"), Err(CapacityError(())));
+    assert_eq!(text, "lolka!");
+    assert_eq!(text.push_str("ab"), Ok(()));
+    assert_eq!(text.push('c'), Err(CapacityError('c')));
+    assert_eq!(text.as_str(), "lolka!ab");
+}
+
+#[test]
+fn test_static_string_multi_byte() {
+    let mut text = StaticString::<5>::new();
+
+    assert_eq!(text.push('ё'), Ok(()));
+    assert_eq!(text.len(), 2);
+    assert_eq!(text.push('€'), Ok(()));
+    assert_eq!(text.len(), 5);
+    assert_eq!(text, "ё€");
+
+    text.clear();
+    assert_eq!(text.push_str("ab"), Ok(()));
+    //4 bytes char doesn't fit into 3 remaining bytes
+    assert_eq!(text.push('🦀'), Err(CapacityError('🦀')));
+    assert_eq!(text.push_str("ёё"), Err(CapacityError(())));
+    assert_eq!(text.push('ё'), Ok(()));
+    assert_eq!(text, "abё");
+    assert_eq!(text.chars().count(), 3);
+}
+
+#[test]
+fn test_static_string_fmt() {
+    let mut text = StaticString::<16>::new();
+
+    let two = "two";
+    write!(text, "{}-{}", 1, two).expect("Success");
+    assert_eq!(text, "1-two");
+    assert_eq!(format!("{}", text), "1-two");
+    assert_eq!(format!("{:?}", text), "\"1-two\"");
+
+    assert!(write!(text, "{}", 1234567890.5).is_err());
+}
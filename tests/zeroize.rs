@@ -0,0 +1,54 @@
+#![cfg(feature = "zeroize")]
+
+use core::mem::ManuallyDrop;
+
+use statiki::{Array, ZeroizingArray};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+//Reads `len` bytes of storage, regardless of current length
+fn storage<const C: usize>(array: &Array<u8, C>, len: usize) -> Vec<u8> {
+    assert!(len <= C);
+    unsafe {
+        core::slice::from_raw_parts(array.as_ptr(), len).to_vec()
+    }
+}
+
+#[test]
+fn test_array_zeroize() {
+    let mut array = Array::<u8, 8>::new();
+    array.extend_from_slice(b"secret");
+    array.truncate(4);
+
+    array.zeroize();
+    assert!(array.is_empty());
+    //Only initialized portion is wiped
+    assert_eq!(storage(&array, 6), b"\0\0\0\0et");
+
+    array.extend_from_slice(b"pass");
+    array.zeroize_full();
+    assert!(array.is_empty());
+    assert_eq!(storage(&array, 8), [0; 8]);
+}
+
+#[test]
+fn test_zeroizing_array() {
+    fn assert_zeroize_on_drop<T: ZeroizeOnDrop>(_: &T) {}
+
+    let mut array = ManuallyDrop::new(ZeroizingArray::<u8, 8>::new());
+    assert_zeroize_on_drop(&*array);
+    array.extend_from_slice(b"password");
+    array.truncate(2);
+    assert_eq!(**array, *b"pa");
+
+    unsafe {
+        ManuallyDrop::drop(&mut array);
+    }
+    //Storage stays in place, as it is owned by ManuallyDrop
+    assert!(array.is_empty());
+    assert_eq!(storage(&array, 8), [0; 8]);
+
+    let mut array = ZeroizingArray::from(Array::<u8, 4>::from_const_array(*b"key!"));
+    array.zeroize();
+    assert!(array.is_empty());
+    assert_eq!(storage(&array, 4), [0; 4]);
+}